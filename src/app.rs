@@ -3,16 +3,65 @@ use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use windows::Win32::Foundation::{HWND, RECT};
 use windows::Win32::Graphics::Gdi::{MONITOR_DEFAULTTONEAREST, MonitorFromWindow};
+use windows::Win32::UI::WindowsAndMessaging::WINDOWPLACEMENT;
 
-use crate::models::{AppProfile, MonitorInfo, SavedData};
+use crate::models::{
+    AppProfile, DEFAULT_WORKSPACE, LaunchWatch, MonitorInfo, SavedData, ThemeMode,
+    WindowPlacementMode, WorkspaceIndex,
+};
 use crate::monitor::get_all_monitors;
 use crate::window::{
-    ProcessEntry, find_window_by_process_name, list_visible_windows, move_window_once,
-    wait_for_window, wait_for_window_by_name,
+    Placement, ProcessEntry, find_window_by_process_name, list_visible_windows,
+    move_window_verified, wait_for_window, wait_for_window_by_name,
 };
 
 // ─── Application State ───────────────────────────────────────────────────────
 
+/// Set by background threads that changed `SavedData` and want it persisted.
+/// The UI thread saves on its next frame, keeping the external-edit check
+/// in step with our own writes.
+pub static CONFIG_DIRTY: AtomicBool = AtomicBool::new(false);
+
+/// Set by the headless `--launch` mode: status lines also go to stdout, and
+/// there is no DisplayWarp window to hide while a profile runs.
+pub static HEADLESS: AtomicBool = AtomicBool::new(false);
+
+/// How long a profile's pre-launch or post-exit command may run before it
+/// is stopped.
+const PROFILE_COMMAND_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// How long a relaunch waits for the previous launch's exit restores, which
+/// may include a post-exit command.
+const RELAUNCH_RESTORE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(90);
+
+/// How long a primary switch waits for confirmation before reverting itself.
+pub const PRIMARY_REVERT_SECS: u64 = 15;
+
+/// Display changes a launch made, undone once the launched app exits.
+#[derive(Default)]
+struct DisplayRestores {
+    /// Mode the target monitor had before `target_resolution` was applied.
+    mode: Option<crate::monitor::DisplayMode>,
+    /// Scaling before `target_scale_percent` was applied.
+    scale: Option<u32>,
+    /// HDR state before `enable_hdr` was applied.
+    hdr: Option<bool>,
+    /// Topology from before `disable_other_monitors` turned monitors off.
+    topology: Option<crate::monitor::DisplayTopology>,
+    /// Monitor layout from before `force_primary` moved the primary.
+    layout: Option<Vec<crate::models::SavedMonitorPos>>,
+}
+
+impl DisplayRestores {
+    fn is_empty(&self) -> bool {
+        self.mode.is_none()
+            && self.scale.is_none()
+            && self.hdr.is_none()
+            && self.topology.is_none()
+            && self.layout.is_none()
+    }
+}
+
 #[derive(PartialEq, Clone, Copy)]
 pub enum AppTab {
     Warp,
@@ -34,7 +83,12 @@ pub struct WindowManagerApp {
     pub selected_mon_idx: usize,
     pub new_profile_window_process: String,
     pub new_profile_launch_args: String,
+    pub new_profile_working_dir: String,
     pub new_profile_window_title: String,
+    pub new_profile_follow_cursor: bool,
+    pub new_profile_watch_secs: u64,
+    /// Set when a file is dropped onto the window so the form scrolls into view.
+    pub scroll_to_new_profile: bool,
     // ── Edit profile form state ──
     pub editing_profile_idx: Option<usize>,
     pub edit_profile_name: String,
@@ -42,14 +96,61 @@ pub struct WindowManagerApp {
     pub edit_profile_mon_idx: usize,
     pub edit_profile_window_process: String,
     pub edit_profile_launch_args: String,
+    pub edit_profile_working_dir: String,
+    pub edit_profile_pre_launch_cmd: String,
+    pub edit_profile_post_exit_cmd: String,
     pub edit_profile_window_title: String,
+    pub edit_profile_wait_for_path: String,
+    /// 0 = default timeout.
+    pub edit_profile_window_timeout_secs: u64,
+    pub edit_profile_keep_attached: bool,
+    pub edit_profile_run_as_admin: bool,
+    pub edit_profile_move_all_windows: bool,
+    pub edit_profile_always_on_top: bool,
+    pub edit_profile_force_primary: bool,
+    pub edit_profile_display_mode: Option<crate::monitor::DisplayMode>,
+    /// Modes the edited profile's monitor supports, by device name. Listed
+    /// when the form opens or the monitor changes, not every frame.
+    pub edit_profile_modes: Option<(String, Vec<crate::monitor::DisplayMode>)>,
+    pub edit_profile_scale_percent: Option<u32>,
+    pub edit_profile_enable_hdr: Option<bool>,
+    pub edit_profile_disable_others: bool,
+    pub edit_profile_placement: WindowPlacementMode,
+    pub edit_profile_borderless: bool,
+    pub edit_profile_geometry: Option<(crate::models::SerializableRect, bool)>,
+    pub edit_profile_hotkey: String,
+    /// True while the edit form waits for a key combo to bind.
+    pub recording_hotkey: bool,
+    pub edit_profile_blank_others: bool,
+    pub edit_profile_follow_cursor: bool,
+    pub edit_profile_hide_self: bool,
+    pub edit_profile_minimize_others: bool,
+    pub edit_profile_restore_others: bool,
+    pub edit_profile_launch_watch: LaunchWatch,
+    pub edit_profile_launch_watch_secs: u64,
+    pub edit_profile_then_launch: Option<String>,
     // ── Live-process mover state ──
     pub live_processes: Vec<ProcessEntry>,
     pub selected_live_process_idx: usize,
+    /// Sort the live window list by title instead of exe name.
+    pub live_sort_by_title: bool,
     pub live_move_mon_idx: usize,
+    pub live_move_all_windows: bool,
+    /// Region of the target monitor a live move sends the window to.
+    pub live_move_placement: WindowPlacementMode,
+    /// Monitor being renamed inline from the preview, and the text so far.
+    pub renaming_monitor: Option<(usize, String)>,
+    /// Placements from before each live move, newest last, for "Undo Move".
+    /// One entry per click; all-windows moves record every window.
+    pub move_undo_stack: Vec<Vec<(isize, WINDOWPLACEMENT)>>,
     // ── Display Profile state ──
     pub new_display_profile_name: String,
     pub dragging_monitor_idx: Option<usize>,
+    /// The Arrange Monitors canvas has been dragged but not applied yet.
+    pub layout_dirty: bool,
+    /// When to re-enumerate monitors after a `WM_DISPLAYCHANGE`. Delayed so
+    /// Windows can finish rearranging the desktop first.
+    pub display_refresh_at: Option<std::time::Instant>,
     pub selected_display_idx: Option<usize>,
     pub drag_start_pos: Option<egui::Pos2>,
     pub original_monitor_rect: Option<windows::Win32::Foundation::RECT>,
@@ -63,16 +164,70 @@ pub struct WindowManagerApp {
     pub logo_texture_white: Option<eframe::egui::TextureHandle>,
     // ── Audio state ──
     pub audio_devices: Vec<crate::audio::AudioDeviceInfo>,
+    pub audio_input_devices: Vec<crate::audio::AudioDeviceInfo>,
+    /// System default output as of the last refresh, marked in the combos.
+    pub default_audio_device_id: Option<String>,
     pub new_profile_audio_device_idx: usize,
     pub edit_profile_audio_device_idx: usize,
+    /// 0 = leave the microphone alone, otherwise 1 + index into `audio_input_devices`.
+    pub new_profile_audio_input_idx: usize,
+    pub edit_profile_audio_input_idx: usize,
+    pub new_profile_volume: Option<f32>,
+    pub edit_profile_volume: Option<f32>,
+    pub edit_profile_audio_fallbacks: Vec<String>,
+    pub edit_profile_audio_settle_ms: u32,
+    pub edit_profile_restore_audio: bool,
     // ── Shared ──
     pub status_message: Arc<parking_lot::Mutex<String>>,
     pub status_log: Arc<parking_lot::Mutex<Vec<String>>>,
     pub markdown_cache: egui_commonmark::CommonMarkCache,
+    // ── Workspaces ──
+    pub new_workspace_name: String,
+    /// `workspaces.json` and the active workspace's config file, as of the
+    /// last load, so the Settings tab doesn't read them every frame.
+    pub workspace_index: WorkspaceIndex,
+    pub config_path: std::path::PathBuf,
     // ── Theme ──
     pub dark_mode: bool,
     // ── Startup State ──
     pub first_frame_hidden: bool,
+    // ── External config edits ──
+    /// Modified time of the config file as of our last load/save.
+    pub config_mtime: parking_lot::Mutex<Option<std::time::SystemTime>>,
+    pub last_config_check: std::time::Instant,
+    /// True while the "config changed on disk" prompt is shown.
+    pub config_conflict: bool,
+    /// Profile exes found missing at the last load, save or monitor refresh,
+    /// so the profile cards don't stat the disk every frame.
+    pub missing_exes: parking_lot::Mutex<std::collections::HashSet<std::path::PathBuf>>,
+    /// Profiles read by Import, waiting for the user to pick merge or replace.
+    pub pending_profile_import: Option<Vec<AppProfile>>,
+    // ── Path fixer ──
+    pub fix_paths_from: String,
+    pub fix_paths_to: String,
+    // ── Primary switch ──
+    /// Monitor positions before the last primary switch, for undo.
+    pub primary_switch_snapshot: Option<Vec<crate::models::SavedMonitorPos>>,
+    /// While set, the "keep this layout?" prompt is shown and the switch is
+    /// reverted automatically once this passes.
+    pub primary_revert_deadline: Option<std::time::Instant>,
+    /// Profile whose Delete button was clicked, awaiting confirmation.
+    pub pending_delete_idx: Option<usize>,
+    /// Profile whose Relaunch button was clicked, awaiting confirmation.
+    pub pending_relaunch_idx: Option<usize>,
+    /// The one-time warning for "turn off other monitors" is open.
+    pub show_disable_monitors_warning: bool,
+    /// The "close DisplayWarp?" prompt is open, and its "remember" box.
+    pub show_close_dialog: bool,
+    pub close_remember_choice: bool,
+    // ── Quick move ──
+    /// Foreground window captured by the hotkey thread, waiting for the UI.
+    pub quick_move_pending: Arc<parking_lot::Mutex<Option<isize>>>,
+    /// Window the open quick-move popup will move; `None` when it is closed.
+    pub quick_move_hwnd: Option<isize>,
+    // ── Startup health ──
+    /// Problems found by [`Self::run_health_check`]; empty means healthy.
+    pub health_issues: Vec<String>,
 }
 
 impl Default for WindowManagerApp {
@@ -90,19 +245,58 @@ impl Default for WindowManagerApp {
             selected_mon_idx: 0,
             new_profile_window_process: String::new(),
             new_profile_launch_args: String::new(),
+            new_profile_working_dir: String::new(),
             new_profile_window_title: String::new(),
+            new_profile_follow_cursor: false,
+            new_profile_watch_secs: 45,
+            scroll_to_new_profile: false,
             editing_profile_idx: None,
             edit_profile_name: String::new(),
             edit_profile_exe: None,
             edit_profile_mon_idx: 0,
             edit_profile_window_process: String::new(),
             edit_profile_launch_args: String::new(),
+            edit_profile_working_dir: String::new(),
+            edit_profile_pre_launch_cmd: String::new(),
+            edit_profile_post_exit_cmd: String::new(),
             edit_profile_window_title: String::new(),
+            edit_profile_wait_for_path: String::new(),
+            edit_profile_window_timeout_secs: 0,
+            edit_profile_keep_attached: false,
+            edit_profile_run_as_admin: false,
+            edit_profile_move_all_windows: false,
+            edit_profile_always_on_top: false,
+            edit_profile_force_primary: false,
+            edit_profile_display_mode: None,
+            edit_profile_modes: None,
+            edit_profile_scale_percent: None,
+            edit_profile_enable_hdr: None,
+            edit_profile_disable_others: false,
+            edit_profile_placement: WindowPlacementMode::Auto,
+            edit_profile_borderless: false,
+            edit_profile_geometry: None,
+            edit_profile_hotkey: String::new(),
+            recording_hotkey: false,
+            edit_profile_blank_others: false,
+            edit_profile_follow_cursor: false,
+            edit_profile_hide_self: false,
+            edit_profile_minimize_others: false,
+            edit_profile_restore_others: false,
+            edit_profile_launch_watch: LaunchWatch::default(),
+            edit_profile_launch_watch_secs: 45,
+            edit_profile_then_launch: None,
             live_processes: vec![],
             selected_live_process_idx: 0,
+            live_sort_by_title: false,
             live_move_mon_idx: 0,
+            live_move_all_windows: false,
+            live_move_placement: WindowPlacementMode::default(),
+            move_undo_stack: Vec::new(),
+            renaming_monitor: None,
             new_display_profile_name: String::new(),
             dragging_monitor_idx: None,
+            layout_dirty: false,
+            display_refresh_at: None,
             selected_display_idx: None,
             drag_start_pos: None,
             original_monitor_rect: None,
@@ -113,21 +307,66 @@ impl Default for WindowManagerApp {
             logo_texture: None,
             logo_texture_white: None,
             audio_devices: vec![],
+            audio_input_devices: vec![],
+            default_audio_device_id: None,
             new_profile_audio_device_idx: 0,
             edit_profile_audio_device_idx: 0,
+            new_profile_audio_input_idx: 0,
+            edit_profile_audio_input_idx: 0,
+            new_profile_volume: None,
+            edit_profile_volume: None,
+            edit_profile_audio_fallbacks: vec![],
+            edit_profile_audio_settle_ms: 0,
+            edit_profile_restore_audio: true,
             status_message: Arc::new(parking_lot::Mutex::new(String::from("Ready."))),
             status_log: Arc::new(parking_lot::Mutex::new(vec!["Ready.".to_string()])),
             markdown_cache: egui_commonmark::CommonMarkCache::default(),
+            new_workspace_name: String::new(),
+            workspace_index: WorkspaceIndex::default(),
+            config_path: std::path::PathBuf::new(),
             dark_mode: true,
             first_frame_hidden: false,
+            config_mtime: parking_lot::Mutex::new(None),
+            last_config_check: std::time::Instant::now(),
+            config_conflict: false,
+            missing_exes: parking_lot::Mutex::new(std::collections::HashSet::new()),
+            pending_profile_import: None,
+            fix_paths_from: String::new(),
+            fix_paths_to: String::new(),
+            primary_switch_snapshot: None,
+            primary_revert_deadline: None,
+            pending_delete_idx: None,
+            pending_relaunch_idx: None,
+            show_disable_monitors_warning: false,
+            show_close_dialog: false,
+            close_remember_choice: false,
+            quick_move_pending: Arc::new(parking_lot::Mutex::new(None)),
+            quick_move_hwnd: None,
+            health_issues: vec![],
         };
         app.refresh_monitors();
         app.refresh_audio_devices();
         app.refresh_live_processes();
         app.load_data();
+        app.dark_mode = match app.data.lock().theme {
+            ThemeMode::Dark => true,
+            ThemeMode::Light => false,
+            ThemeMode::Auto => crate::tray::is_dark_mode(),
+        };
 
         // Start the background watcher thread.
-        Self::start_watcher(Arc::clone(&data), Arc::clone(&watcher_running));
+        Self::start_watcher(
+            Arc::clone(&data),
+            Arc::clone(&watcher_running),
+            Arc::clone(&app.status_message),
+            Arc::clone(&app.status_log),
+        );
+        Self::start_monitor_audio_follow(
+            Arc::clone(&data),
+            Arc::clone(&watcher_running),
+            Arc::clone(&app.status_message),
+            Arc::clone(&app.status_log),
+        );
 
         app
     }
@@ -147,7 +386,12 @@ impl WindowManagerApp {
         let mut l = log.lock();
         // Prepend a simple timestamp
         let ts = Self::local_time_str();
-        l.push(format!("[{ts}] {msg}"));
+        let line = format!("[{ts}] {msg}");
+        crate::file_log::append(line.clone());
+        if HEADLESS.load(Ordering::Relaxed) {
+            println!("{line}");
+        }
+        l.push(line);
         if l.len() > 200 {
             l.remove(0);
         }
@@ -162,7 +406,8 @@ impl WindowManagerApp {
             || msg.starts_with("📁")
             || msg.starts_with("⚙️");
 
-        if should_toast {
+        // A headless launch already prints to the console.
+        if should_toast && !HEADLESS.load(Ordering::Relaxed) {
             // Strip the emoji prefix for the toast title/text if we want,
             // or just show the whole thing. Let's show the whole thing as text1.
             let toast_msg = msg.clone();
@@ -184,20 +429,67 @@ impl WindowManagerApp {
 
     /// Returns current local time as HH:MM:SS string for log timestamps.
     fn local_time_str() -> String {
-        let secs = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_secs();
-        // UTC seconds of day — close enough for log display
-        let s = secs % 86400;
-        let h = s / 3600;
-        let m = (s % 3600) / 60;
-        let sec = s % 60;
-        format!("{h:02}:{m:02}:{sec:02}")
+        let t = unsafe { windows::Win32::System::SystemInformation::GetLocalTime() };
+        format!("{:02}:{:02}:{:02}", t.wHour, t.wMinute, t.wSecond)
+    }
+
+    /// Pre-fill the new-profile form from a dropped `.exe` or `.lnk` file.
+    pub fn handle_dropped_file(&mut self, path: std::path::PathBuf) {
+        let name = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        let exe = if crate::shortcut::is_shortcut(&path) {
+            match crate::shortcut::resolve_shortcut(&path) {
+                Some((target, args)) => {
+                    if !args.trim().is_empty() {
+                        self.new_profile_launch_args = args;
+                    }
+                    target
+                }
+                None => {
+                    Self::push_status(
+                        &self.status_message,
+                        &self.status_log,
+                        format!("❌ Could not resolve shortcut: {}", path.display()),
+                    );
+                    return;
+                }
+            }
+        } else {
+            path
+        };
+
+        let is_exe = exe
+            .extension()
+            .map(|e| e.eq_ignore_ascii_case("exe"))
+            .unwrap_or(false);
+        if !is_exe {
+            Self::push_status(
+                &self.status_message,
+                &self.status_log,
+                format!("❌ Not an executable: {}", exe.display()),
+            );
+            return;
+        }
+
+        self.new_profile_exe = Some(exe);
+        self.new_profile_name = name;
+        self.editing_profile_idx = None;
+        self.current_tab = AppTab::Warp;
+        self.scroll_to_new_profile = true;
+        Self::push_status(
+            &self.status_message,
+            &self.status_log,
+            "📂 File dropped — review the new profile and click Create.",
+        );
     }
 
     pub fn refresh_monitors(&mut self) {
         self.monitors = get_all_monitors();
+        self.refresh_missing_exes();
+        self.layout_dirty = false;
         self.display_targets = crate::monitor::get_all_display_targets();
         let max = self.monitors.len().saturating_sub(1);
         self.selected_mon_idx = self.selected_mon_idx.min(max);
@@ -205,59 +497,723 @@ impl WindowManagerApp {
         self.live_move_mon_idx = self.live_move_mon_idx.min(max);
     }
 
+    /// Remove the profile at `i` and save, keeping `editing_profile_idx` and
+    /// the pending delete/relaunch confirmations on the same profile (or
+    /// clearing them if that one was deleted).
+    pub fn delete_profile(&mut self, i: usize) {
+        let name = {
+            let mut data = self.data.lock();
+            if i >= data.profiles.len() {
+                return;
+            }
+            data.profiles.remove(i).name
+        };
+        let follow = |idx: Option<usize>| match idx {
+            Some(e) if e == i => None,
+            Some(e) if e > i => Some(e - 1),
+            other => other,
+        };
+        self.editing_profile_idx = follow(self.editing_profile_idx);
+        self.pending_delete_idx = follow(self.pending_delete_idx);
+        self.pending_relaunch_idx = follow(self.pending_relaunch_idx);
+        self.save_data();
+        Self::push_status(
+            &self.status_message,
+            &self.status_log,
+            format!("🗑 Profile deleted: {}", name),
+        );
+    }
+
+    /// Move the profile at `from` to position `to` and save, keeping
+    /// `editing_profile_idx` and the pending delete/relaunch confirmations on
+    /// the same profile.
+    pub fn move_profile(&mut self, from: usize, to: usize) {
+        {
+            let mut data = self.data.lock();
+            if from == to || from >= data.profiles.len() || to >= data.profiles.len() {
+                return;
+            }
+            let profile = data.profiles.remove(from);
+            data.profiles.insert(to, profile);
+        }
+        let follow = |idx: Option<usize>| {
+            idx.map(|e| {
+                if e == from {
+                    to
+                } else if from < e && e <= to {
+                    e - 1
+                } else if to <= e && e < from {
+                    e + 1
+                } else {
+                    e
+                }
+            })
+        };
+        self.editing_profile_idx = follow(self.editing_profile_idx);
+        self.pending_delete_idx = follow(self.pending_delete_idx);
+        self.pending_relaunch_idx = follow(self.pending_relaunch_idx);
+        self.save_data();
+    }
+
+    /// Re-enumerate monitors after Windows reported a display change, and log
+    /// profiles whose target monitor appeared or disappeared.
+    pub fn handle_display_change(&mut self) {
+        let profiles = self.data.lock().profiles.clone();
+        let had_target: Vec<bool> = profiles
+            .iter()
+            .map(|p| Self::resolve_monitor(&self.monitors, p).is_some())
+            .collect();
+        let before = self.monitors.len();
+        // An unapplied Arrange Monitors drag survives the refresh for the
+        // monitors still connected.
+        let dragged: Option<Vec<(String, RECT)>> = self.layout_dirty.then(|| {
+            self.monitors
+                .iter()
+                .map(|m| (m.device_name.clone(), m.rect))
+                .collect()
+        });
+        self.refresh_monitors();
+        if let Some(dragged) = dragged {
+            for m in &mut self.monitors {
+                if let Some((_, rect)) = dragged.iter().find(|(d, _)| *d == m.device_name) {
+                    m.rect = *rect;
+                }
+            }
+            self.layout_dirty = true;
+        }
+        if self.monitors.len() != before {
+            Self::push_status(
+                &self.status_message,
+                &self.status_log,
+                format!(
+                    "🖥 Display configuration changed — {} monitor(s) connected.",
+                    self.monitors.len()
+                ),
+            );
+        }
+        for (p, had) in profiles.iter().zip(had_target) {
+            if p.follow_cursor {
+                continue;
+            }
+            match (had, Self::resolve_monitor(&self.monitors, p).is_some()) {
+                (false, true) => Self::push_status(
+                    &self.status_message,
+                    &self.status_log,
+                    format!("🔌 Target monitor of '{}' is back.", p.name),
+                ),
+                (true, false) => Self::push_status(
+                    &self.status_message,
+                    &self.status_log,
+                    format!("⚠️ Target monitor of '{}' was disconnected.", p.name),
+                ),
+                _ => {}
+            }
+        }
+    }
+
+    /// Re-list visible windows, keeping the selected window selected if it
+    /// still exists.
     pub fn refresh_live_processes(&mut self) {
+        let selected = self.selected_live_hwnd();
         self.live_processes = list_visible_windows();
-        self.selected_live_process_idx = 0;
+        self.sort_live_processes_keeping(selected);
+    }
+
+    /// Apply `live_sort_by_title`, keeping the selection on the same window.
+    pub fn sort_live_processes(&mut self) {
+        self.sort_live_processes_keeping(self.selected_live_hwnd());
+    }
+
+    fn selected_live_hwnd(&self) -> Option<HWND> {
+        self.live_processes
+            .get(self.selected_live_process_idx)
+            .map(|e| e.hwnd)
+    }
+
+    /// Sort the live list and move the selection to `selected`, or to the top
+    /// if that window is gone.
+    fn sort_live_processes_keeping(&mut self, selected: Option<HWND>) {
+        if self.live_sort_by_title {
+            self.live_processes
+                .sort_by_cached_key(|e| e.title.to_lowercase());
+        } else {
+            self.live_processes
+                .sort_by_cached_key(|e| e.exe_name.to_lowercase());
+        }
+        self.selected_live_process_idx = selected
+            .and_then(|hwnd| self.live_processes.iter().position(|e| e.hwnd == hwnd))
+            .unwrap_or(0);
+    }
+
+    /// Remember where `hwnds` are now so the next move can be undone.
+    pub fn record_move_undo(&mut self, hwnds: &[HWND]) {
+        const MOVE_UNDO_LIMIT: usize = 10;
+        let saved: Vec<(isize, WINDOWPLACEMENT)> = hwnds
+            .iter()
+            .filter_map(|&h| crate::window::get_window_placement(h).map(|p| (h.0 as isize, p)))
+            .collect();
+        if saved.is_empty() {
+            return;
+        }
+        self.move_undo_stack.push(saved);
+        if self.move_undo_stack.len() > MOVE_UNDO_LIMIT {
+            self.move_undo_stack.remove(0);
+        }
+    }
+
+    /// Put the windows from the most recent live move back where they were.
+    pub fn undo_last_move(&mut self) {
+        let Some(saved) = self.move_undo_stack.pop() else {
+            return;
+        };
+        let restored = saved
+            .iter()
+            .filter(|(hwnd, placement)| {
+                crate::window::restore_window_placement(HWND(*hwnd as *mut _), placement)
+            })
+            .count();
+        let msg = if restored == 0 {
+            "❌ Window no longer exists (it may have been closed).".to_string()
+        } else {
+            format!("↩️ Move undone ({restored} window(s) restored).")
+        };
+        Self::push_status(&self.status_message, &self.status_log, msg);
+    }
+
+    /// The user's label for monitor `idx`, or "Monitor N" when none is set.
+    pub fn monitor_label(&self, idx: usize) -> String {
+        self.monitors
+            .get(idx)
+            .and_then(|m| self.data.lock().monitor_label(m).cloned())
+            .unwrap_or_else(|| format!("Monitor {}", idx + 1))
     }
 
     pub fn refresh_audio_devices(&mut self) {
+        // Combo selections are 1 + list index, so carry them over by id.
+        use crate::audio::AudioDeviceInfo;
+        fn id_at(devices: &[AudioDeviceInfo], idx: usize) -> Option<String> {
+            idx.checked_sub(1)
+                .and_then(|i| devices.get(i))
+                .map(|d| d.id.clone())
+        }
+        fn idx_of(devices: &[AudioDeviceInfo], id: Option<String>) -> usize {
+            id.and_then(|id| devices.iter().position(|d| d.id == id))
+                .map_or(0, |i| i + 1)
+        }
+
         if let Ok(devices) = crate::audio::get_audio_output_devices() {
+            let new_id = id_at(&self.audio_devices, self.new_profile_audio_device_idx);
+            let edit_id = id_at(&self.audio_devices, self.edit_profile_audio_device_idx);
             self.audio_devices = devices;
+            self.new_profile_audio_device_idx = idx_of(&self.audio_devices, new_id);
+            self.edit_profile_audio_device_idx = idx_of(&self.audio_devices, edit_id);
         }
+        if let Ok(devices) = crate::audio::get_audio_input_devices() {
+            let new_id = id_at(&self.audio_input_devices, self.new_profile_audio_input_idx);
+            let edit_id = id_at(&self.audio_input_devices, self.edit_profile_audio_input_idx);
+            self.audio_input_devices = devices;
+            self.new_profile_audio_input_idx = idx_of(&self.audio_input_devices, new_id);
+            self.edit_profile_audio_input_idx = idx_of(&self.audio_input_devices, edit_id);
+        }
+        self.default_audio_device_id = crate::audio::get_default_audio_device_id().ok();
     }
 
-    pub fn get_config_path() -> std::path::PathBuf {
-        let exe_dir = std::env::current_exe()
+    /// Where config files live: the folder named in `config_location.txt`
+    /// beside the exe if set, otherwise `%APPDATA%\DisplayWarp`.
+    pub fn get_config_dir() -> std::path::PathBuf {
+        let exe_dir = Self::exe_dir();
+        if let Ok(content) = std::fs::read_to_string(exe_dir.join("config_location.txt")) {
+            let path = std::path::PathBuf::from(content.trim());
+            if path.is_dir() {
+                return path;
+            }
+        }
+
+        static DEFAULT_DIR: std::sync::OnceLock<std::path::PathBuf> = std::sync::OnceLock::new();
+        DEFAULT_DIR
+            .get_or_init(|| {
+                let Some(appdata) = std::env::var_os("APPDATA") else {
+                    return exe_dir.clone();
+                };
+                let dir = std::path::PathBuf::from(appdata).join("DisplayWarp");
+                if std::fs::create_dir_all(&dir).is_err() {
+                    return exe_dir.clone();
+                }
+                Self::migrate_config_from(&exe_dir, &dir);
+                dir
+            })
+            .clone()
+    }
+
+    pub fn exe_dir() -> std::path::PathBuf {
+        std::env::current_exe()
             .unwrap_or_else(|_| std::path::PathBuf::from("."))
             .parent()
             .unwrap_or_else(|| std::path::Path::new("."))
-            .to_path_buf();
+            .to_path_buf()
+    }
 
-        if let Ok(content) = std::fs::read_to_string(exe_dir.join("config_location.txt")) {
-            let path = std::path::PathBuf::from(content.trim());
-            if path.is_dir() {
-                return path.join("monitor_config.json");
+    /// One-time move of config files that older versions kept beside the exe.
+    /// Runs only while the new folder has no config of its own.
+    fn migrate_config_from(old_dir: &std::path::Path, new_dir: &std::path::Path) {
+        if new_dir.join("monitor_config.json").exists() {
+            return;
+        }
+        let Ok(entries) = std::fs::read_dir(old_dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            if !Self::is_config_file(&name) {
+                continue;
+            }
+            let target = new_dir.join(&*name);
+            // Moving across drives fails; fall back to copy and delete.
+            if std::fs::rename(entry.path(), &target).is_err()
+                && std::fs::copy(entry.path(), &target).is_ok()
+            {
+                let _ = std::fs::remove_file(entry.path());
+            }
+        }
+    }
+
+    /// The workspace index and every workspace's config file.
+    fn is_config_file(name: &str) -> bool {
+        name == "workspaces.json"
+            || (name.starts_with("monitor_config.") && name.ends_with(".json"))
+    }
+
+    /// Point `config_location.txt` at `folder`, copying every workspace and
+    /// the workspace index there under their own names. Files the folder
+    /// already has are kept, and are what gets loaded.
+    pub fn relocate_config(&mut self, folder: &std::path::Path) {
+        let old_dir = Self::get_config_dir();
+        if let Ok(entries) = std::fs::read_dir(&old_dir) {
+            for entry in entries.flatten() {
+                let name = entry.file_name();
+                if !Self::is_config_file(&name.to_string_lossy()) {
+                    continue;
+                }
+                let target = folder.join(&name);
+                if !target.exists() {
+                    let _ = std::fs::copy(entry.path(), &target);
+                }
+            }
+        }
+        if let Err(e) = std::fs::write(
+            Self::exe_dir().join("config_location.txt"),
+            folder.to_string_lossy().as_ref(),
+        ) {
+            Self::push_status(
+                &self.status_message,
+                &self.status_log,
+                format!("❌ Could not change the config location: {e}"),
+            );
+            return;
+        }
+        self.load_data();
+        self.refresh_tray();
+        Self::push_status(
+            &self.status_message,
+            &self.status_log,
+            "📁 Config location updated.",
+        );
+    }
+
+    /// Config file of the active workspace.
+    pub fn get_config_path() -> std::path::PathBuf {
+        Self::workspace_config_path(&Self::load_workspace_index().active)
+    }
+
+    /// The default workspace keeps the historical `monitor_config.json` name.
+    pub fn workspace_config_path(name: &str) -> std::path::PathBuf {
+        let file = if name == DEFAULT_WORKSPACE {
+            "monitor_config.json".to_string()
+        } else {
+            let slug: String = name
+                .chars()
+                .map(|c| {
+                    if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                        c
+                    } else {
+                        '_'
+                    }
+                })
+                .collect();
+            format!("monitor_config.{slug}.json")
+        };
+        Self::get_config_dir().join(file)
+    }
+
+    pub fn load_workspace_index() -> WorkspaceIndex {
+        std::fs::read(Self::get_config_dir().join("workspaces.json"))
+            .ok()
+            .and_then(|bytes| serde_json::from_slice::<WorkspaceIndex>(&bytes).ok())
+            .filter(|idx| idx.workspaces.contains(&idx.active))
+            .unwrap_or_default()
+    }
+
+    fn save_workspace_index(index: &WorkspaceIndex) {
+        if let Ok(json) = serde_json::to_string_pretty(index) {
+            let _ = write_atomic(&Self::get_config_dir().join("workspaces.json"), &json);
+        }
+    }
+
+    /// Create a new, empty workspace that inherits the current app settings.
+    pub fn create_workspace(&mut self, name: &str) {
+        let name = name.trim();
+        let mut index = Self::load_workspace_index();
+        if name.is_empty() || index.workspaces.iter().any(|w| w == name) {
+            Self::push_status(
+                &self.status_message,
+                &self.status_log,
+                "❌ Workspace name is empty or already exists.",
+            );
+            return;
+        }
+        // Names that differ only in punctuation share a file name.
+        let path = Self::workspace_config_path(name);
+        if let Some(taken) = index
+            .workspaces
+            .iter()
+            .find(|w| Self::workspace_config_path(w) == path)
+        {
+            Self::push_status(
+                &self.status_message,
+                &self.status_log,
+                format!("❌ Workspace name is too close to '{taken}'; pick another."),
+            );
+            return;
+        }
+
+        let fresh = {
+            let d = self.data.lock();
+            SavedData {
+                close_action: d.close_action,
+                start_minimized: d.start_minimized,
+                start_on_boot: d.start_on_boot,
+                start_without_focus: d.start_without_focus,
+                watcher_interval_secs: d.watcher_interval_secs,
+                theme: d.theme,
+                high_contrast: d.high_contrast,
+                font_scale: d.font_scale,
+                monitor_labels: d.monitor_labels.clone(),
+                monitor_audio: d.monitor_audio.clone(),
+                next_monitor_hotkey: d.next_monitor_hotkey.clone(),
+                disable_monitors_confirmed: d.disable_monitors_confirmed,
+                ..SavedData::default()
             }
+        };
+        if let Ok(json) = serde_json::to_string_pretty(&fresh) {
+            let _ = write_atomic(&path, &json);
+        }
+        index.workspaces.push(name.to_string());
+        Self::save_workspace_index(&index);
+        self.switch_workspace(name);
+    }
+
+    /// Make `name` the active workspace and reload profiles from its file.
+    pub fn switch_workspace(&mut self, name: &str) {
+        let mut index = Self::load_workspace_index();
+        if !index.workspaces.iter().any(|w| w == name) {
+            return;
         }
+        index.active = name.to_string();
+        Self::save_workspace_index(&index);
 
-        exe_dir.join("monitor_config.json")
+        *self.data.lock() = SavedData::default();
+        self.load_data();
+        self.editing_profile_idx = None;
+
+        self.refresh_tray();
+        Self::push_status(
+            &self.status_message,
+            &self.status_log,
+            format!("📁 Switched to workspace '{name}'."),
+        );
     }
 
     pub fn load_data(&mut self) {
-        if let Ok(bytes) = std::fs::read(Self::get_config_path()) {
-            if let Ok(decoded) = serde_json::from_slice::<SavedData>(&bytes) {
-                *self.data.lock() = decoded;
+        self.workspace_index = Self::load_workspace_index();
+        let path = Self::workspace_config_path(&self.workspace_index.active);
+        self.config_path = path.clone();
+        let mut backed_up = false;
+        if let Ok(bytes) = std::fs::read(&path) {
+            match serde_json::from_slice::<SavedData>(&bytes) {
+                Ok(mut decoded) => {
+                    if std::mem::take(&mut decoded.close_to_tray) {
+                        decoded.close_action = crate::models::CloseAction::MinimizeToTray;
+                    }
+                    *self.data.lock() = decoded;
+                }
+                Err(e) => backed_up = self.recover_corrupt_config(&path, &bytes, &e),
+            }
+        }
+        *self.config_mtime.lock() = Self::config_file_mtime();
+        if backed_up {
+            // Replace the bad file now that a backup exists. Without one it is
+            // left alone, as the only copy of the user's config.
+            self.save_data();
+        }
+        crate::hotkey::reload_profile_hotkeys();
+        self.sync_start_on_boot();
+
+        let missing: Vec<String> = self
+            .refresh_missing_exes()
+            .iter()
+            .map(|name| format!("'{name}'"))
+            .collect();
+        if !missing.is_empty() {
+            Self::push_status(
+                &self.status_message,
+                &self.status_log,
+                format!(
+                    "⚠️ Executable missing for {} — fix the path in Edit.",
+                    missing.join(", ")
+                ),
+            );
+        }
+    }
+
+    /// The config failed to parse: copy it aside, then salvage what we can.
+    /// If the JSON itself is intact, every setting and profile that still
+    /// deserializes is kept and malformed profiles are skipped; otherwise
+    /// DisplayWarp starts from defaults. Returns whether the backup was
+    /// written.
+    fn recover_corrupt_config(
+        &self,
+        path: &std::path::Path,
+        bytes: &[u8],
+        error: &serde_json::Error,
+    ) -> bool {
+        let secs = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let mut backup = path.as_os_str().to_owned();
+        backup.push(format!(".bak.{secs}"));
+        let backup = std::path::PathBuf::from(backup);
+        let backed_up = std::fs::write(&backup, bytes).is_ok();
+
+        let salvaged = serde_json::from_slice::<serde_json::Value>(bytes)
+            .ok()
+            .and_then(|mut value| {
+                let profiles = value.get_mut("profiles").map(serde_json::Value::take);
+                value
+                    .as_object_mut()?
+                    .insert("profiles".into(), serde_json::Value::Array(Vec::new()));
+                let mut data = serde_json::from_value::<SavedData>(value).unwrap_or_default();
+                let entries = match profiles {
+                    Some(serde_json::Value::Array(entries)) => entries,
+                    _ => Vec::new(),
+                };
+                let total = entries.len();
+                data.profiles = entries
+                    .into_iter()
+                    .filter_map(|p| serde_json::from_value::<AppProfile>(p).ok())
+                    .collect();
+                Some((data, total))
+            });
+
+        let backup_note = if backed_up {
+            format!("backed up to {}", backup.display())
+        } else {
+            "backup failed, so the file is left as is".to_string()
+        };
+        let msg = match salvaged {
+            Some((data, total)) => {
+                let kept = data.profiles.len();
+                *self.data.lock() = data;
+                format!(
+                    "⚠️ Config was corrupt ({error}); {backup_note}. Recovered {kept} of {total} profile(s)."
+                )
+            }
+            None => {
+                *self.data.lock() = SavedData::default();
+                format!("⚠️ Config was corrupt ({error}); {backup_note}; started fresh.")
             }
+        };
+        Self::push_status(&self.status_message, &self.status_log, msg);
+        backed_up
+    }
+
+    /// Persist an explicit theme choice and apply it immediately.
+    pub fn set_theme(&mut self, theme: ThemeMode) {
+        self.data.lock().theme = theme;
+        match theme {
+            ThemeMode::Dark => self.dark_mode = true,
+            ThemeMode::Light => self.dark_mode = false,
+            ThemeMode::Auto => self.dark_mode = crate::tray::is_dark_mode(),
+        }
+        self.save_data();
+    }
+
+    /// Quick startup diagnostic: config parses, monitors and audio devices
+    /// enumerate, and the tray icon exists. Failures are logged with a hint.
+    pub fn run_health_check(&mut self) {
+        let mut issues = Vec::new();
+
+        let config_path = Self::get_config_path();
+        if let Ok(bytes) = std::fs::read(&config_path)
+            && let Err(e) = serde_json::from_slice::<SavedData>(&bytes)
+        {
+            issues.push(format!(
+                "Config could not be parsed ({e}). Fix or remove {} to start from defaults.",
+                config_path.display()
+            ));
         }
+        if self.monitors.is_empty() {
+            issues.push(
+                "No monitors detected. Check display drivers, then press Refresh Monitor.".into(),
+            );
+        }
+        if let Err(e) = crate::audio::get_audio_output_devices() {
+            issues.push(format!(
+                "Audio enumeration failed ({e}). COM may not be available; audio switching is disabled."
+            ));
+        }
+        if self.tray.is_none() {
+            issues.push(
+                "Tray icon could not be created. Is Explorer running? Close-to-tray will hide the window with no way back.".into(),
+            );
+        }
+
+        if issues.is_empty() {
+            Self::push_status(
+                &self.status_message,
+                &self.status_log,
+                "✅ Health check passed.",
+            );
+        }
+        for issue in &issues {
+            Self::push_status(
+                &self.status_message,
+                &self.status_log,
+                format!("❌ Health check: {issue}"),
+            );
+        }
+        self.health_issues = issues;
     }
 
     pub fn save_data(&self) {
+        if self.config_changed_externally() {
+            Self::push_status(
+                &self.status_message,
+                &self.status_log,
+                "⚠️ Config was edited outside DisplayWarp — reload or overwrite it first.",
+            );
+            return;
+        }
         let data = self.data.lock();
-        if let Ok(json) = serde_json::to_string_pretty(&*data) {
-            let _ = std::fs::write(Self::get_config_path(), json);
+        let written = serde_json::to_string_pretty(&*data)
+            .map_err(std::io::Error::from)
+            .and_then(|json| write_atomic(&Self::get_config_path(), &json));
+        drop(data);
+        if let Err(e) = written {
+            Self::push_status(
+                &self.status_message,
+                &self.status_log,
+                format!("❌ Could not save config: {e}"),
+            );
+            return;
+        }
+        *self.config_mtime.lock() = Self::config_file_mtime();
+        crate::hotkey::reload_profile_hotkeys();
+        self.refresh_missing_exes();
+        self.refresh_tray();
+    }
+
+    /// Re-check which profile exes exist, for the cards' missing-exe flag.
+    /// Returns the names of the profiles whose exe is missing.
+    pub fn refresh_missing_exes(&self) -> Vec<String> {
+        let missing: Vec<(String, std::path::PathBuf)> = self
+            .data
+            .lock()
+            .profiles
+            .iter()
+            .filter(|p| !p.exe_path.exists())
+            .map(|p| (p.name.clone(), p.exe_path.clone()))
+            .collect();
+        *self.missing_exes.lock() = missing.iter().map(|(_, exe)| exe.clone()).collect();
+        missing.into_iter().map(|(name, _)| name).collect()
+    }
+
+    /// Rebuild the tray menu from the current profiles and layouts.
+    pub fn refresh_tray(&self) {
+        let (profiles, display_profiles) = {
+            let d = self.data.lock();
+            (d.profiles.clone(), d.display_profiles.clone())
+        };
+        if let Some(t) = &self.tray {
+            t.refresh_menu(&profiles, &display_profiles);
         }
     }
 
+    fn config_file_mtime() -> Option<std::time::SystemTime> {
+        std::fs::metadata(Self::get_config_path())
+            .and_then(|m| m.modified())
+            .ok()
+    }
+
+    /// True if the config file on disk was modified since we last loaded or saved it.
+    pub fn config_changed_externally(&self) -> bool {
+        let current = Self::config_file_mtime();
+        current.is_some() && current != *self.config_mtime.lock()
+    }
+
+    /// Discard our in-memory state and re-read the config from disk.
+    pub fn reload_data(&mut self) {
+        self.load_data();
+        self.refresh_tray();
+        Self::push_status(
+            &self.status_message,
+            &self.status_log,
+            "📁 Config reloaded from disk.",
+        );
+    }
+
+    /// Accept the external edit as seen and write our in-memory state over it.
+    pub fn overwrite_config(&self) {
+        *self.config_mtime.lock() = Self::config_file_mtime();
+        self.save_data();
+    }
+
+    /// The HKCU `Run` entry for DisplayWarp. Login launches pass
+    /// `--minimized` so the app starts hidden in the tray.
     pub fn get_auto_launch() -> auto_launch::AutoLaunch {
         let app_path =
             std::env::current_exe().unwrap_or_else(|_| std::path::PathBuf::from("DisplayWarp.exe"));
         auto_launch::AutoLaunchBuilder::new()
             .set_app_name("DisplayWarp")
             .set_app_path(&app_path.to_string_lossy())
+            .set_args(&["--minimized"])
             .build()
             .unwrap()
     }
 
+    /// True when started with `--minimized` or `--tray` (e.g. from the login
+    /// entry), which hides to the tray regardless of `start_minimized`.
+    pub fn minimized_flag() -> bool {
+        std::env::args()
+            .skip(1)
+            .any(|a| a == "--minimized" || a == "--tray")
+    }
+
+    /// Make `start_on_boot` match the registry, which the user may have
+    /// changed in Task Manager, and re-point an enabled entry at this exe in
+    /// case it was moved.
+    fn sync_start_on_boot(&self) {
+        let al = Self::get_auto_launch();
+        let Ok(enabled) = al.is_enabled() else {
+            return;
+        };
+        if enabled {
+            let _ = al.enable();
+        }
+        self.data.lock().start_on_boot = enabled;
+    }
+
     /// Find the live rect for a monitor by device name.
     pub fn find_monitor_rect(monitors: &[MonitorInfo], device_name: &str) -> Option<RECT> {
         monitors
@@ -266,9 +1222,31 @@ impl WindowManagerApp {
             .map(|m| m.rect)
     }
 
+    /// The monitor a profile targets: stable id match first, then device
+    /// name. `None` means the caller should fall back to the cached rect.
+    pub fn resolve_monitor<'a>(
+        monitors: &'a [MonitorInfo],
+        profile: &AppProfile,
+    ) -> Option<&'a MonitorInfo> {
+        profile
+            .target_monitor_id
+            .as_deref()
+            .and_then(|id| monitors.iter().find(|m| m.stable_id.as_deref() == Some(id)))
+            .or_else(|| {
+                monitors
+                    .iter()
+                    .find(|m| m.device_name == profile.target_monitor_name)
+            })
+    }
+
     // ─── Background watcher ──────────────────────────────────────────────
 
-    fn start_watcher(data: Arc<parking_lot::Mutex<SavedData>>, running: Arc<AtomicBool>) {
+    fn start_watcher(
+        data: Arc<parking_lot::Mutex<SavedData>>,
+        running: Arc<AtomicBool>,
+        status: Arc<parking_lot::Mutex<String>>,
+        log: Arc<parking_lot::Mutex<Vec<String>>>,
+    ) {
         std::thread::spawn(move || {
             while running.load(Ordering::Relaxed) {
                 // Dynamically grab the user interval to sleep
@@ -282,32 +1260,47 @@ impl WindowManagerApp {
                 let profiles: Vec<AppProfile> = { data.lock().profiles.clone() };
                 let monitors = get_all_monitors();
 
-                for profile in &profiles {
-                    if !profile.persistent_monitor {
-                        continue;
+                // One window search per profile per tick, shared by the
+                // running dots on the cards and the enforcement below.
+                let windows: Vec<Option<HWND>> =
+                    profiles.iter().map(Self::find_profile_window).collect();
+                {
+                    let mut d = data.lock();
+                    for (profile, hwnd) in profiles.iter().zip(&windows) {
+                        if let Some(p) = d.profiles.iter_mut().find(|p| p.name == profile.name) {
+                            p.is_running = hwnd.is_some();
+                        }
                     }
-                    let proc_name = match &profile.window_process_name {
-                        Some(name) if !name.is_empty() => name.to_lowercase(),
-                        _ => continue,
-                    };
+                }
 
-                    // find_window_by_process_name returns Option<HWND> — fine for watcher
-                    let hwnd = match find_window_by_process_name(&proc_name) {
-                        Some(h) => h,
-                        None => continue,
+                for (profile, hwnd) in profiles.iter().zip(windows) {
+                    // Follow-cursor profiles have no fixed monitor to enforce.
+                    if !profile.persistent_monitor || profile.follow_cursor {
+                        continue;
+                    }
+                    if profile
+                        .enforce_paused_until
+                        .is_some_and(|until| std::time::Instant::now() < until)
+                    {
+                        continue;
+                    }
+                    let Some(hwnd) = hwnd else {
+                        continue;
                     };
+                    if profile.always_on_top && !crate::window::is_always_on_top(hwnd) {
+                        crate::window::set_always_on_top(hwnd, true);
+                    }
 
-                    let target_rect =
-                        Self::find_monitor_rect(&monitors, &profile.target_monitor_name).or_else(
-                            || {
-                                profile.target_monitor_rect.as_ref().map(|r| RECT {
-                                    left: r.left,
-                                    top: r.top,
-                                    right: r.right,
-                                    bottom: r.bottom,
-                                })
-                            },
-                        );
+                    let live_rect = Self::resolve_monitor(&monitors, profile).map(|m| m.rect);
+                    let from_live = live_rect.is_some();
+                    let target_rect = live_rect.or_else(|| {
+                        profile.target_monitor_rect.as_ref().map(|r| RECT {
+                            left: r.left,
+                            top: r.top,
+                            right: r.right,
+                            bottom: r.bottom,
+                        })
+                    });
                     let target_rect = match target_rect {
                         Some(r) => r,
                         None => continue,
@@ -329,10 +1322,131 @@ impl WindowManagerApp {
                     let current_mon = unsafe { MonitorFromWindow(hwnd, MONITOR_DEFAULTTONEAREST) };
 
                     if current_mon != target_mon {
-                        move_window_once(hwnd, target_rect);
+                        Self::log_target(
+                            &status,
+                            &log,
+                            &format!("Watcher ({})", profile.name),
+                            &profile.target_monitor_name,
+                            &target_rect,
+                            from_live,
+                        );
+                        match &profile.saved_placement {
+                            Some(r) => crate::window::apply_window_geometry(
+                                hwnd,
+                                r.to_rect(),
+                                profile.saved_maximized,
+                            ),
+                            None => {
+                                let placement =
+                                    move_window_verified(hwnd, target_rect, profile.placement);
+                                Self::report_placement(&status, &log, &placement);
+                            }
+                        }
+                        if profile.borderless_fullscreen {
+                            crate::window::make_borderless(hwnd, target_rect);
+                        }
+                    }
+                }
+            }
+        });
+    }
+
+    /// Switch the default audio output when the foreground window moves onto
+    /// a monitor listed in `monitor_audio`. The window has to stay there for
+    /// a moment first, so alt-tabbing across monitors doesn't thrash the
+    /// audio endpoint.
+    fn start_monitor_audio_follow(
+        data: Arc<parking_lot::Mutex<SavedData>>,
+        running: Arc<AtomicBool>,
+        status: Arc<parking_lot::Mutex<String>>,
+        log: Arc<parking_lot::Mutex<Vec<String>>>,
+    ) {
+        const POLL: std::time::Duration = std::time::Duration::from_millis(400);
+        const SETTLE: std::time::Duration = std::time::Duration::from_millis(1500);
+
+        std::thread::spawn(move || {
+            unsafe {
+                let _ = windows::Win32::System::Com::CoInitializeEx(
+                    None,
+                    windows::Win32::System::Com::COINIT_APARTMENTTHREADED,
+                );
+            }
+            // Monitor the foreground window is on, and since when.
+            let mut seen: Option<(String, std::time::Instant)> = None;
+            // Monitor last handled, so each crossing switches at most once.
+            let mut handled: Option<String> = None;
+            while running.load(Ordering::Relaxed) {
+                std::thread::sleep(POLL);
+                if data.lock().monitor_audio.is_empty() {
+                    continue;
+                }
+                let hwnd =
+                    unsafe { windows::Win32::UI::WindowsAndMessaging::GetForegroundWindow() };
+                if hwnd.is_invalid() {
+                    continue;
+                }
+                let Some(device) = crate::monitor::monitor_of_window(hwnd) else {
+                    continue;
+                };
+                if seen.as_ref().is_none_or(|(d, _)| *d != device) {
+                    seen = Some((device, std::time::Instant::now()));
+                    continue;
+                }
+                if seen
+                    .as_ref()
+                    .is_some_and(|(_, since)| since.elapsed() < SETTLE)
+                    || handled.as_ref() == Some(&device)
+                {
+                    continue;
+                }
+                handled = Some(device.clone());
+
+                let Some(monitor) = get_all_monitors()
+                    .into_iter()
+                    .find(|m| m.device_name == device)
+                else {
+                    continue;
+                };
+                let (audio_id, name) = {
+                    let d = data.lock();
+                    let audio_id = d.monitor_audio.get(monitor.label_key()).cloned();
+                    let name = d
+                        .monitor_label(&monitor)
+                        .cloned()
+                        .or_else(|| monitor.hardware_name.clone())
+                        .unwrap_or_else(|| monitor.device_name.clone());
+                    (audio_id, name)
+                };
+                let Some(audio_id) = audio_id else {
+                    continue;
+                };
+                if crate::audio::get_default_audio_device_id().is_ok_and(|cur| cur == audio_id) {
+                    continue;
+                }
+                if !crate::audio::device_connected(&audio_id) {
+                    Self::push_status(
+                        &status,
+                        &log,
+                        format!(
+                            "⚠️ Saved audio device for {name} not connected, keeping system default."
+                        ),
+                    );
+                    continue;
+                }
+                match crate::audio::set_default_audio_device(&audio_id) {
+                    Ok(_) => {
+                        Self::push_status(&status, &log, format!("🔊 Audio switched for {name}."))
                     }
+                    Err(e) => Self::push_status(
+                        &status,
+                        &log,
+                        format!("⚠️ Audio switch for {name} failed: {e}"),
+                    ),
                 }
             }
+            unsafe {
+                windows::Win32::System::Com::CoUninitialize();
+            }
         });
     }
 
@@ -342,37 +1456,397 @@ impl WindowManagerApp {
         status_message: Arc<parking_lot::Mutex<String>>,
         status_log: Arc<parking_lot::Mutex<Vec<String>>>,
     ) {
-        crate::monitor::restore_monitor_layout(&layout.monitors);
+        let msg = match crate::monitor::restore_monitor_layout(&layout.monitors) {
+            Ok(()) => format!("✅ Applied '{}'", layout.name),
+            Err(e) => format!("❌ Could not apply '{}': {e}", layout.name),
+        };
+        Self::push_status(&status_message, &status_log, msg);
+    }
+
+    /// Make `device_name` primary, keeping the previous layout so the switch
+    /// can be undone. Starts the auto-revert countdown.
+    pub fn switch_primary(&mut self, device_name: &str) {
+        let snapshot: Vec<crate::models::SavedMonitorPos> = self
+            .monitors
+            .iter()
+            .map(|m| crate::models::SavedMonitorPos {
+                device_name: m.device_name.clone(),
+                rect: m.rect.into(),
+                mode: None,
+            })
+            .collect();
+        if !crate::monitor::switch_primary_to(device_name, &self.monitors) {
+            Self::push_status(
+                &self.status_message,
+                &self.status_log,
+                format!("❌ Monitor '{device_name}' not found."),
+            );
+            return;
+        }
+        self.primary_switch_snapshot = Some(snapshot);
+        self.primary_revert_deadline =
+            Some(std::time::Instant::now() + std::time::Duration::from_secs(PRIMARY_REVERT_SECS));
+        std::thread::sleep(std::time::Duration::from_millis(500));
+        self.refresh_monitors();
         Self::push_status(
-            &status_message,
-            &status_log,
-            format!("✅ Applied '{}'", layout.name),
+            &self.status_message,
+            &self.status_log,
+            format!("🖥️ Primary monitor switched to {device_name}."),
         );
     }
 
+    /// Apply the positions dragged on the Arrange Monitors canvas, keeping the
+    /// current primary at (0, 0). Uses the same undo/countdown as
+    /// [`Self::switch_primary`]. Applied off the UI thread; the monitors are
+    /// re-read once the change settles.
+    pub fn apply_arranged_layout(&mut self) {
+        let live = get_all_monitors();
+        let snapshot: Vec<crate::models::SavedMonitorPos> = live
+            .iter()
+            .map(|m| crate::models::SavedMonitorPos {
+                device_name: m.device_name.clone(),
+                rect: m.rect.into(),
+                mode: None,
+            })
+            .collect();
+        let (dx, dy) = live
+            .iter()
+            .find(|m| m.rect.left == 0 && m.rect.top == 0)
+            .and_then(|p| {
+                self.monitors
+                    .iter()
+                    .find(|m| m.device_name == p.device_name)
+            })
+            .map(|m| (m.rect.left, m.rect.top))
+            .unwrap_or((0, 0));
+        let arranged: Vec<crate::models::SavedMonitorPos> = self
+            .monitors
+            .iter()
+            .map(|m| crate::models::SavedMonitorPos {
+                device_name: m.device_name.clone(),
+                rect: RECT {
+                    left: m.rect.left - dx,
+                    top: m.rect.top - dy,
+                    right: m.rect.right - dx,
+                    bottom: m.rect.bottom - dy,
+                }
+                .into(),
+                mode: None,
+            })
+            .collect();
+        self.layout_dirty = false;
+        self.primary_switch_snapshot = Some(snapshot);
+        self.primary_revert_deadline =
+            Some(std::time::Instant::now() + std::time::Duration::from_secs(PRIMARY_REVERT_SECS));
+        let status = Arc::clone(&self.status_message);
+        let log = Arc::clone(&self.status_log);
+        std::thread::spawn(move || {
+            let msg = match crate::monitor::restore_monitor_layout(&arranged) {
+                Ok(()) => "🖥️ Monitor arrangement applied.".to_string(),
+                Err(e) => format!("❌ Could not apply the monitor arrangement: {e}"),
+            };
+            Self::push_status(&status, &log, msg);
+            // Also covers a rejected layout, which Windows doesn't broadcast,
+            // so the canvas drops the dragged positions either way.
+            crate::monitor::notify_display_changed();
+        });
+    }
+
+    /// Put monitor positions back to how they were before the last primary switch.
+    pub fn undo_primary_switch(&mut self) {
+        self.primary_revert_deadline = None;
+        let Some(snapshot) = self.primary_switch_snapshot.take() else {
+            return;
+        };
+        let msg = match crate::monitor::restore_monitor_layout(&snapshot) {
+            Ok(()) => "↩️ Restored the previous monitor layout.".to_string(),
+            Err(e) => format!("❌ Could not restore the previous monitor layout: {e}"),
+        };
+        std::thread::sleep(std::time::Duration::from_millis(500));
+        self.refresh_monitors();
+        Self::push_status(&self.status_message, &self.status_log, msg);
+    }
+
+    /// Profiles whose `exe_path` starts with `from` (case-insensitive, whole
+    /// path components only), paired with the path rewritten onto `to`.
+    pub fn rebase_exe_paths(
+        profiles: &[AppProfile],
+        from: &str,
+        to: &str,
+    ) -> Vec<(usize, std::path::PathBuf)> {
+        let from = from.trim().trim_end_matches(['\\', '/']);
+        let to = to.trim().trim_end_matches(['\\', '/']);
+        if from.is_empty() || to.is_empty() {
+            return vec![];
+        }
+        profiles
+            .iter()
+            .enumerate()
+            .filter_map(|(i, p)| {
+                let old = p.exe_path.to_string_lossy();
+                let head = old.get(..from.len())?;
+                let rest = &old[from.len()..];
+                if !head.eq_ignore_ascii_case(from)
+                    || !(rest.is_empty() || rest.starts_with(['\\', '/']))
+                {
+                    return None;
+                }
+                Some((i, std::path::PathBuf::from(format!("{to}{rest}"))))
+            })
+            .collect()
+    }
+
     // ─── Profile launching ───────────────────────────────────────────────
 
     pub fn launch_profile(
         profile: &AppProfile,
+        data: Arc<parking_lot::Mutex<SavedData>>,
+        status: Arc<parking_lot::Mutex<String>>,
+        log: Arc<parking_lot::Mutex<Vec<String>>>,
+    ) {
+        // Off the calling (usually UI) thread: the steps before the spawn can
+        // block for a while, e.g. a pre-launch command or a display mode switch.
+        let profile = profile.clone();
+        std::thread::spawn(move || {
+            Self::launch_chained(&profile, data, Vec::new(), status, log);
+        });
+    }
+
+    /// [`launch_profile`](Self::launch_profile) that returns only when the
+    /// launch is finished: the window is placed (or the search gave up), the
+    /// chain has run, and any exit-time restores have happened, which means
+    /// waiting for the app to exit when the profile has some. Returns `false`
+    /// if the launch failed before the app was started.
+    pub fn launch_profile_blocking(
+        profile: &AppProfile,
+        data: Arc<parking_lot::Mutex<SavedData>>,
+        status: Arc<parking_lot::Mutex<String>>,
+        log: Arc<parking_lot::Mutex<Vec<String>>>,
+    ) -> bool {
+        match Self::launch_chained(profile, data, Vec::new(), status, log) {
+            Some(launch) => {
+                let _ = launch.join();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The window `profile` is currently showing, found the way the watcher
+    /// finds it: by the window process name, else the launched process if it
+    /// is still running, else the exe's file name.
+    pub fn find_profile_window(profile: &AppProfile) -> Option<HWND> {
+        let title_match = profile.window_title_match.as_deref();
+        match &profile.window_process_name {
+            Some(name) if !name.is_empty() => {
+                find_window_by_process_name(&name.to_lowercase(), title_match)
+            }
+            // No separate window process: the launched exe owns the
+            // window. Prefer the exact PID we started so another
+            // copy of the same exe is left alone.
+            // A PID whose start time changed now belongs to another process.
+            _ => match profile.launched_process {
+                Some((pid, started)) if crate::window::process_start_time(pid) == Some(started) => {
+                    crate::window::find_main_window_by_pid(pid)
+                }
+                _ => profile.exe_path.file_name().and_then(|n| {
+                    find_window_by_process_name(&n.to_string_lossy().to_lowercase(), title_match)
+                }),
+            },
+        }
+    }
+
+    /// Kill the process behind `profile`'s window, wait for it to exit, and
+    /// launch the profile again. Launches straight away if it isn't running.
+    pub fn relaunch_profile(
+        profile: &AppProfile,
+        data: Arc<parking_lot::Mutex<SavedData>>,
         status: Arc<parking_lot::Mutex<String>>,
         log: Arc<parking_lot::Mutex<Vec<String>>>,
     ) {
+        let profile = profile.clone();
+        std::thread::spawn(move || {
+            let previous = data
+                .lock()
+                .profiles
+                .iter()
+                .find(|p| p.name == profile.name)
+                .and_then(|p| p.launch_done.clone());
+            let pid = Self::find_profile_window(&profile)
+                .map(crate::window::window_pid)
+                .filter(|&pid| pid != 0);
+            match pid {
+                Some(pid) => {
+                    // Pinned to the process that owns the window right now.
+                    let started = crate::window::process_start_time(pid);
+                    if !crate::window::terminate_process(pid, started) {
+                        Self::push_status(
+                            &status,
+                            &log,
+                            format!(
+                                "❌ Could not stop '{}' (PID {pid}); it may be running as administrator.",
+                                profile.name
+                            ),
+                        );
+                        return;
+                    }
+                    Self::push_status(
+                        &status,
+                        &log,
+                        format!("🔁 Stopped '{}' (PID {pid}), relaunching.", profile.name),
+                    );
+                    // The previous launch thread restores displays and audio
+                    // on this exit; launching first would snapshot its
+                    // overrides as the state to restore.
+                    if let Some(previous) = previous
+                        && !previous.wait(RELAUNCH_RESTORE_TIMEOUT)
+                    {
+                        Self::push_status(
+                            &status,
+                            &log,
+                            format!(
+                                "⚠️ The previous launch of '{}' is still restoring after {}s; relaunching anyway.",
+                                profile.name,
+                                RELAUNCH_RESTORE_TIMEOUT.as_secs()
+                            ),
+                        );
+                    }
+                }
+                None => Self::push_status(
+                    &status,
+                    &log,
+                    format!("🔁 '{}' isn't running, launching it.", profile.name),
+                ),
+            }
+            Self::launch_chained(&profile, data, Vec::new(), status, log);
+        });
+    }
+
+    /// Launch `profile`, then follow its `then_launch` chain. `visited` holds
+    /// the profiles already launched by this chain so cycles stop. Returns the
+    /// launch thread, or `None` if the launch failed before starting it.
+    fn launch_chained(
+        profile: &AppProfile,
+        data: Arc<parking_lot::Mutex<SavedData>>,
+        mut visited: Vec<String>,
+        status: Arc<parking_lot::Mutex<String>>,
+        log: Arc<parking_lot::Mutex<Vec<String>>>,
+    ) -> Option<std::thread::JoinHandle<()>> {
+        visited.push(profile.name.clone());
+        let then_launch = profile.then_launch.clone().filter(|s| !s.is_empty());
         let exe = profile.exe_path.clone();
-        let device_name = profile.target_monitor_name.clone();
+        if !exe.exists() {
+            Self::push_status(
+                &status,
+                &log,
+                format!("❌ Executable not found: {}", exe.display()),
+            );
+            return None;
+        }
+        let live_monitors = get_all_monitors();
+        let device_name = if profile.follow_cursor {
+            crate::monitor::monitor_under_cursor()
+                .unwrap_or_else(|| profile.target_monitor_name.clone())
+        } else {
+            Self::resolve_monitor(&live_monitors, profile)
+                .map(|m| m.device_name.clone())
+                .unwrap_or_else(|| profile.target_monitor_name.clone())
+        };
         let window_process_name = profile.window_process_name.clone();
-        let audio_device_id = profile.target_audio_device_id.clone();
+        let wait_for_path = profile.wait_for_path.clone();
+        let window_timeout_secs = profile.window_timeout_secs;
+        let hide_self_on_launch = profile.hide_self_on_launch;
+        let audio_settle_ms = profile.audio_settle_ms;
+        let restore_audio_on_exit = profile.restore_audio_on_exit;
+        let minimize_others = profile.minimize_others_on_launch;
+        let restore_others = profile.restore_others_on_exit;
+        let watch_secs = match profile.launch_watch {
+            LaunchWatch::OneShot => Some(0),
+            LaunchWatch::Timed => Some(profile.launch_watch_secs),
+            LaunchWatch::UntilClosed => None,
+        };
+        let audio_input = profile.target_audio_input_id.clone();
+        let target_volume = profile.target_volume;
+        let audio_preferences: Vec<String> = profile
+            .target_audio_device_id
+            .iter()
+            .chain(&profile.audio_device_preferences)
+            .cloned()
+            .collect();
         let launch_args = profile.launch_args.clone();
-        let _window_title_match = profile.window_title_match.clone();
+        let working_dir = profile.working_dir.clone();
+        let pre_launch_cmd = profile
+            .pre_launch_cmd
+            .clone()
+            .filter(|s| !s.trim().is_empty());
+        let post_exit_cmd = profile
+            .post_exit_cmd
+            .clone()
+            .filter(|s| !s.trim().is_empty());
+        let keep_attached = profile.keep_attached;
+        let run_as_admin = profile.run_as_admin;
+        let move_all_windows = profile.move_all_windows;
+        let always_on_top = profile.always_on_top;
+        let blank_other_monitors = profile.blank_other_monitors;
+        let force_primary = profile.force_primary && !profile.follow_cursor;
+        let target_resolution = profile.target_resolution;
+        let target_refresh_hz = profile.target_refresh_hz;
+        let target_scale_percent = profile.target_scale_percent;
+        let enable_hdr = profile.enable_hdr;
+        let disable_other_monitors = profile.disable_other_monitors;
+        let window_placement = profile.placement;
+        let borderless = profile.borderless_fullscreen;
+        let saved_geometry = profile
+            .saved_placement
+            .as_ref()
+            .map(|r| (r.to_rect(), profile.saved_maximized));
+        let window_title_match = profile.window_title_match.clone().filter(|s| !s.is_empty());
 
-        let live_monitors = get_all_monitors();
-        let target_rect = Self::find_monitor_rect(&live_monitors, &device_name).or_else(|| {
-            profile.target_monitor_rect.as_ref().map(|r| RECT {
-                left: r.left,
-                top: r.top,
-                right: r.right,
-                bottom: r.bottom,
-            })
-        });
+        let live_rect = Self::find_monitor_rect(&live_monitors, &device_name);
+        let target_rect = match live_rect {
+            Some(live) => {
+                // Self-heal the cached fallback rect when the monitor's mode
+                // changed, and the device name / id when Windows renumbered it.
+                let cached = profile.target_monitor_rect.as_ref().map(|r| r.to_rect());
+                let live_id = live_monitors
+                    .iter()
+                    .find(|m| m.device_name == device_name)
+                    .and_then(|m| m.stable_id.clone());
+                if !profile.follow_cursor
+                    && (cached != Some(live)
+                        || device_name != profile.target_monitor_name
+                        || (live_id.is_some() && live_id != profile.target_monitor_id))
+                {
+                    if let Some(p) = data
+                        .lock()
+                        .profiles
+                        .iter_mut()
+                        .find(|p| p.name == profile.name)
+                    {
+                        p.target_monitor_rect = Some(live.into());
+                        p.target_monitor_name = device_name.clone();
+                        if live_id.is_some() {
+                            p.target_monitor_id = live_id;
+                        }
+                    }
+                    CONFIG_DIRTY.store(true, Ordering::Relaxed);
+                }
+                Some(live)
+            }
+            None => {
+                let cached = profile.target_monitor_rect.as_ref().map(|r| r.to_rect());
+                if cached.is_some() {
+                    Self::push_status(
+                        &status,
+                        &log,
+                        format!(
+                            "⚠️ Monitor '{device_name}' not connected — using its cached rect, which may be stale."
+                        ),
+                    );
+                }
+                cached
+            }
+        };
         let target_rect = match target_rect {
             Some(r) => r,
             None => {
@@ -381,31 +1855,264 @@ impl WindowManagerApp {
                     &log,
                     format!("❌ Monitor '{}' not found.", device_name),
                 );
-                return;
+                return None;
+            }
+        };
+        // ── Display mode override ────────────────────────────────────────
+        let mut restores = DisplayRestores::default();
+        let (live_monitors, target_rect) = match target_resolution {
+            Some((width, height)) => {
+                let current = crate::monitor::current_display_mode(&device_name);
+                match crate::monitor::set_display_mode(
+                    &device_name,
+                    width,
+                    height,
+                    target_refresh_hz,
+                ) {
+                    Ok(()) => {
+                        Self::push_status(
+                            &status,
+                            &log,
+                            format!("🖥️ Switched {device_name} to {width}×{height}."),
+                        );
+                        restores.mode = current;
+                        std::thread::sleep(std::time::Duration::from_millis(1000));
+                        let switched = get_all_monitors();
+                        let rect =
+                            Self::find_monitor_rect(&switched, &device_name).unwrap_or(target_rect);
+                        (switched, rect)
+                    }
+                    Err(e) => {
+                        Self::push_status(
+                            &status,
+                            &log,
+                            format!("⚠️ Display mode not changed: {e}"),
+                        );
+                        (live_monitors, target_rect)
+                    }
+                }
+            }
+            None => (live_monitors, target_rect),
+        };
+
+        // ── Display scaling override ─────────────────────────────────────
+        if let Some(percent) = target_scale_percent {
+            let current = crate::monitor::current_scale_percent(&device_name);
+            if current != Some(percent) {
+                match crate::monitor::set_scale_percent(&device_name, percent) {
+                    Ok(()) => {
+                        restores.scale = current;
+                        std::thread::sleep(std::time::Duration::from_millis(500));
+                        if crate::monitor::current_scale_percent(&device_name) == Some(percent) {
+                            Self::push_status(
+                                &status,
+                                &log,
+                                format!("🔍 Set {device_name} scaling to {percent}%."),
+                            );
+                        } else {
+                            Self::push_status(
+                                &status,
+                                &log,
+                                format!(
+                                    "⚠️ Windows accepted {percent}% scaling for {device_name} but didn't apply it. The scaling API is undocumented and does nothing on some Windows builds."
+                                ),
+                            );
+                        }
+                    }
+                    Err(e) => Self::push_status(
+                        &status,
+                        &log,
+                        format!(
+                            "⚠️ Display scaling not changed: {e} (the scaling API is undocumented and may not work on this Windows build)"
+                        ),
+                    ),
+                }
+            }
+        }
+
+        // ── HDR override ─────────────────────────────────────────────────
+        if let Some(enable) = enable_hdr {
+            let on_off = if enable { "on" } else { "off" };
+            match crate::monitor::hdr_enabled(&device_name) {
+                None => Self::push_status(
+                    &status,
+                    &log,
+                    format!("⚠️ HDR not supported on this display ({device_name})."),
+                ),
+                Some(current) if current == enable => {}
+                Some(current) => match crate::monitor::set_hdr(&device_name, enable) {
+                    Ok(()) => {
+                        restores.hdr = Some(current);
+                        Self::push_status(
+                            &status,
+                            &log,
+                            format!("🌈 Turned HDR {on_off} for {device_name}."),
+                        );
+                    }
+                    Err(e) => {
+                        Self::push_status(&status, &log, format!("⚠️ HDR not turned {on_off}: {e}"))
+                    }
+                },
+            }
+        }
+
+        // ── Disable other monitors ───────────────────────────────────────
+        // The target ends up as the only (and so primary) monitor, which
+        // makes the force-primary switch below unnecessary.
+        let (live_monitors, target_rect) = if disable_other_monitors {
+            match crate::monitor::disable_other_monitors(&device_name) {
+                Ok(topology) => {
+                    Self::push_status(
+                        &status,
+                        &log,
+                        format!("🖥️ Turned off every monitor except {device_name}."),
+                    );
+                    restores.topology = Some(topology);
+                    std::thread::sleep(std::time::Duration::from_millis(1500));
+                    let switched = get_all_monitors();
+                    let rect =
+                        Self::find_monitor_rect(&switched, &device_name).unwrap_or(target_rect);
+                    (switched, rect)
+                }
+                Err(e) => {
+                    Self::push_status(
+                        &status,
+                        &log,
+                        format!("⚠️ Other monitors not turned off: {e}"),
+                    );
+                    (live_monitors, target_rect)
+                }
+            }
+        } else {
+            (live_monitors, target_rect)
+        };
+
+        // ── Force primary (exclusive fullscreen) ─────────────────────────
+        // Exclusive-fullscreen games open on the primary monitor, so make the
+        // target primary for the session and put the layout back on exit.
+        let (live_monitors, target_rect) = if force_primary && restores.topology.is_none() {
+            let snapshot: Vec<crate::models::SavedMonitorPos> = live_monitors
+                .iter()
+                .map(|m| crate::models::SavedMonitorPos {
+                    device_name: m.device_name.clone(),
+                    rect: m.rect.into(),
+                    mode: None,
+                })
+                .collect();
+            Self::push_status(
+                &status,
+                &log,
+                format!("🖥️ Switching primary to {device_name}…"),
+            );
+            if crate::monitor::switch_primary_to(&device_name, &live_monitors) {
+                restores.layout = Some(snapshot);
+                std::thread::sleep(std::time::Duration::from_millis(1500));
+                let switched = get_all_monitors();
+                let rect = Self::find_monitor_rect(&switched, &device_name).unwrap_or(target_rect);
+                (switched, rect)
+            } else {
+                Self::push_status(
+                    &status,
+                    &log,
+                    "⚠️ Could not switch the primary monitor; launching without it.",
+                );
+                (live_monitors, target_rect)
             }
+        } else {
+            (live_monitors, target_rect)
         };
 
-        let cwd = exe
+        Self::log_target(
+            &status,
+            &log,
+            &format!("Launch ({})", profile.name),
+            &device_name,
+            &target_rect,
+            live_rect.is_some(),
+        );
+
+        let exe_dir = exe
             .parent()
             .unwrap_or(std::path::Path::new("."))
             .to_path_buf();
-        let mut cmd = std::process::Command::new(&exe);
-        cmd.current_dir(&cwd);
-        if let Some(args_str) = launch_args {
-            if !args_str.trim().is_empty() {
-                for arg in args_str.split_whitespace() {
-                    cmd.arg(arg);
-                }
+        let cwd = match working_dir {
+            Some(dir) if dir.is_dir() => dir,
+            Some(dir) => {
+                Self::push_status(
+                    &status,
+                    &log,
+                    format!(
+                        "⚠️ Working folder {} does not exist; using the exe's folder.",
+                        dir.display()
+                    ),
+                );
+                exe_dir
             }
+            None => exe_dir,
+        };
+        if let Some(command) = &pre_launch_cmd {
+            Self::run_profile_command("Pre-launch", command, &cwd, &status, &log);
         }
-        let child = match cmd.spawn() {
-            Ok(c) => c,
+        // Keep the process handle from the spawn itself so exit waits track this
+        // exact process, even if Windows later recycles the PID.
+        let spawned = if run_as_admin {
+            Self::push_status(&status, &log, "🛡 Requesting administrator rights…");
+            crate::elevation::spawn_elevated(&exe, launch_args.as_deref().unwrap_or(""), &cwd)
+        } else {
+            let mut cmd = std::process::Command::new(&exe);
+            cmd.current_dir(&cwd);
+            if !keep_attached {
+                use std::os::windows::process::CommandExt;
+                use windows::Win32::System::Threading::{
+                    CREATE_NEW_PROCESS_GROUP, DETACHED_PROCESS,
+                };
+                cmd.creation_flags(DETACHED_PROCESS.0 | CREATE_NEW_PROCESS_GROUP.0);
+            }
+            if let Some(args_str) = &launch_args {
+                cmd.args(split_launch_args(args_str));
+            }
+            cmd.spawn()
+                .map(|child| (child.id(), child.into()))
+                .map_err(|e| e.to_string())
+        };
+        let launch_done = crate::models::LaunchDone::default();
+        let (pid, process_handle): (u32, std::os::windows::io::OwnedHandle) = match spawned {
+            Ok(p) => p,
             Err(e) => {
                 Self::push_status(&status, &log, format!("❌ Failed to launch: {e}"));
-                return;
+                Self::restore_displays(&status, &log, &device_name, restores);
+                return None;
             }
         };
-        let pid = child.id();
+        if let Some(p) = data
+            .lock()
+            .profiles
+            .iter_mut()
+            .find(|p| p.name == profile.name)
+        {
+            // The handle keeps the PID from being reused, so this reads the
+            // start time of the process just spawned.
+            p.launched_process = crate::window::process_start_time(pid).map(|s| (pid, s));
+            p.launch_done = Some(launch_done.clone());
+        }
+        // Chained launches, joined at the end of the launch thread so a
+        // blocking launch waits for the whole chain.
+        let mut chained = Vec::new();
+        if restores.topology.is_some() {
+            Self::push_status(
+                &status,
+                &log,
+                "🖥️ Other monitors will be turned back on when the app exits.",
+            );
+        }
+        if restores.layout.is_some() {
+            Self::push_status(
+                &status,
+                &log,
+                "🖥️ Primary monitor will be restored when the app exits.",
+            );
+        }
+        let profile_name = profile.name.clone();
         let exe_name = exe
             .file_name()
             .map(|n| n.to_string_lossy().to_string())
@@ -413,7 +2120,7 @@ impl WindowManagerApp {
 
         Self::push_status(&status, &log, format!("🚀 Launched {exe_name} (PID {pid})"));
 
-        std::thread::spawn(move || {
+        Some(std::thread::spawn(move || {
             unsafe {
                 let _ = windows::Win32::System::Com::CoInitializeEx(
                     None,
@@ -422,29 +2129,149 @@ impl WindowManagerApp {
             }
 
             // ── Audio ──────────────────────────────────────────────────────
-            if let Some(ref audio_id) = audio_device_id {
-                match crate::audio::set_default_audio_device(audio_id) {
-                    Ok(_) => Self::push_status(&status, &log, "🔊 Audio device switched."),
+            // Devices to switch back to once the app exits, if any.
+            let mut restore_audio_to = Vec::new();
+            // Device and level to put the master volume back to on exit.
+            let mut restore_volume: Option<(Option<String>, f32)> = None;
+            if let Some(input_id) = &audio_input {
+                let previous = crate::audio::get_default_audio_input_id().ok();
+                let present = crate::audio::get_audio_input_devices()
+                    .is_ok_and(|d| d.iter().any(|d| &d.id == input_id));
+                if !present {
+                    Self::push_status(
+                        &status,
+                        &log,
+                        "⚠️ This profile's microphone is not connected; input left unchanged.",
+                    );
+                } else {
+                    match crate::audio::set_default_audio_device(input_id) {
+                        Ok(_) => {
+                            Self::push_status(&status, &log, "🎙 Microphone switched.");
+                            if restore_audio_on_exit
+                                && let Some(previous) = previous.filter(|p| p != input_id)
+                            {
+                                restore_audio_to.push(previous);
+                            }
+                        }
+                        Err(e) => Self::push_status(
+                            &status,
+                            &log,
+                            format!("⚠️ Microphone switch failed: {e}"),
+                        ),
+                    }
+                }
+            }
+            if !audio_preferences.is_empty() {
+                let previous = crate::audio::get_default_audio_device_id().ok();
+                match crate::audio::first_present_device(&audio_preferences) {
+                    Ok(Some(audio_id)) => match crate::audio::set_default_audio_device(&audio_id) {
+                        Ok(_) => {
+                            if audio_id != audio_preferences[0] {
+                                Self::push_status(
+                                    &status,
+                                    &log,
+                                    "🔊 Preferred audio device not connected — switched to a fallback.",
+                                );
+                            } else {
+                                Self::push_status(&status, &log, "🔊 Audio device switched.");
+                            }
+                            if restore_audio_on_exit
+                                && let Some(previous) = previous.filter(|p| *p != audio_id)
+                            {
+                                restore_audio_to.push(previous);
+                            }
+                        }
+                        Err(e) => {
+                            Self::push_status(&status, &log, format!("⚠️ Audio switch failed: {e}"))
+                        }
+                    },
+                    Ok(None) => Self::push_status(
+                        &status,
+                        &log,
+                        "⚠️ Saved audio device not connected, keeping system default.",
+                    ),
                     Err(e) => {
                         Self::push_status(&status, &log, format!("⚠️ Audio switch failed: {e}"))
                     }
                 }
+                if audio_settle_ms > 0 {
+                    std::thread::sleep(std::time::Duration::from_millis(audio_settle_ms as u64));
+                }
+            }
+            if let Some(level) = target_volume {
+                // After any switch above the profile's device is the default output.
+                let previous = crate::audio::get_master_volume(None).ok();
+                let device = crate::audio::get_default_audio_device_id().ok();
+                match crate::audio::set_master_volume(device.as_deref(), level) {
+                    Ok(_) => {
+                        Self::push_status(
+                            &status,
+                            &log,
+                            format!("🔉 Volume set to {:.0}%.", level * 100.0),
+                        );
+                        if restore_audio_on_exit && let Some(previous) = previous {
+                            restore_volume = Some((device, previous));
+                        }
+                    }
+                    Err(e) => {
+                        Self::push_status(&status, &log, format!("⚠️ Volume change failed: {e}"))
+                    }
+                }
             }
+            let restore_audio = !restore_audio_to.is_empty() || restore_volume.is_some();
 
             // ── Window detection ───────────────────────────────────────────
             let target_w = target_rect.right - target_rect.left;
             let target_h = target_rect.bottom - target_rect.top;
 
-            let found = if let Some(proc_name) = window_process_name.filter(|s| !s.is_empty()) {
+            // An elevated launch goes through the shell, so match its window
+            // by the exe name unless the profile names another process.
+            let window_process_name = window_process_name
+                .filter(|s| !s.is_empty())
+                .or_else(|| run_as_admin.then(|| exe_name.clone()));
+            let timeout_ms: u64 = match window_timeout_secs {
+                Some(secs) => secs * 1000,
+                None if window_process_name.is_some() => 30_000,
+                None => 15_000,
+            };
+
+            // Optional readiness file; its wait counts against the window timeout.
+            let mut waited_ms = 0;
+            if let Some(path) = wait_for_path {
+                Self::push_status(
+                    &status,
+                    &log,
+                    format!("⏳ Waiting for {} to appear…", path.display()),
+                );
+                let start = std::time::Instant::now();
+                while !path.exists() && start.elapsed().as_millis() < timeout_ms as u128 {
+                    std::thread::sleep(std::time::Duration::from_millis(250));
+                }
+                waited_ms = start.elapsed().as_millis() as u64;
+                if !path.exists() {
+                    Self::push_status(
+                        &status,
+                        &log,
+                        format!(
+                            "⚠️ {} did not appear within {}s; looking for the window anyway.",
+                            path.display(),
+                            timeout_ms / 1000
+                        ),
+                    );
+                }
+            }
+            let remaining_ms = timeout_ms.saturating_sub(waited_ms).max(1_000);
+
+            let found = if let Some(proc_name) = window_process_name {
                 Self::push_status(
                     &status,
                     &log,
                     format!("⏳ Waiting for '{proc_name}' window…"),
                 );
-                wait_for_window_by_name(&proc_name, 30_000)
+                wait_for_window_by_name(&proc_name, window_title_match.as_deref(), remaining_ms)
             } else {
                 Self::push_status(&status, &log, format!("⏳ Waiting for PID {pid} window…"));
-                wait_for_window(pid, 15_000)
+                wait_for_window(pid, remaining_ms)
             };
 
             // ── Move ───────────────────────────────────────────────────────
@@ -462,39 +2289,279 @@ impl WindowManagerApp {
                             target_h,
                         ),
                     );
-                    // Phase 1: aggressive initial placement (~6 s). Runs inline so
-                    // we know the window is on the right monitor before reporting done.
-                    move_window_once(f.hwnd, target_rect);
-                    Self::push_status(&status, &log, "✅ Done — window on target monitor.");
-                    // Phase 2: silent 45-second keep-alive watcher in background.
-                    // Does not block the status log.
+                    let mut window_pid: u32 = 0;
+                    unsafe {
+                        windows::Win32::UI::WindowsAndMessaging::GetWindowThreadProcessId(
+                            f.hwnd,
+                            Some(&mut window_pid),
+                        );
+                    }
+
+                    let placed = if let Some((normal, maximized)) = saved_geometry {
+                        // Apps often resize themselves once while starting up,
+                        // so apply the snapshot twice.
+                        crate::window::apply_window_geometry(f.hwnd, normal, maximized);
+                        std::thread::sleep(std::time::Duration::from_millis(500));
+                        crate::window::apply_window_geometry(f.hwnd, normal, maximized);
+                        Self::push_status(
+                            &status,
+                            &log,
+                            "📐 Done — restored the saved window size and position.",
+                        );
+                        true
+                    } else if move_all_windows && window_pid != 0 {
+                        let (found, moved) =
+                            crate::window::move_all_windows_by_pid(window_pid, target_rect);
+                        Self::push_status(
+                            &status,
+                            &log,
+                            if moved == found {
+                                format!("✅ Done — moved {moved} window(s) to the target monitor.")
+                            } else {
+                                format!(
+                                    "⚠️ Moved {moved} of {found} window(s) to the target monitor; Windows refused the rest."
+                                )
+                            },
+                        );
+                        moved > 0
+                    } else {
+                        // Phase 1: aggressive initial placement (~6 s). Runs inline so
+                        // we know the window is on the right monitor before reporting done.
+                        let placement = move_window_verified(f.hwnd, target_rect, window_placement);
+                        let placed = Self::report_placement(&status, &log, &placement);
+                        if placed {
+                            Self::push_status(
+                                &status,
+                                &log,
+                                format!(
+                                    "✅ Done — window locked after {:.1}s.",
+                                    f.elapsed_ms as f32 / 1000.0
+                                ),
+                            );
+                        }
+                        placed
+                    };
+                    if borderless {
+                        crate::window::make_borderless(f.hwnd, target_rect);
+                        Self::push_status(&status, &log, "🔲 Window frame removed (borderless).");
+                    }
+                    if always_on_top && crate::window::set_always_on_top(f.hwnd, true) {
+                        Self::push_status(&status, &log, "📌 Window kept on top.");
+                    }
+                    // Phase 2: silent keep-alive watcher in background, as
+                    // configured per profile. Does not block the status log.
                     let hwnd_raw = f.hwnd.0 as isize;
-                    std::thread::spawn(move || {
-                        crate::window::watch_window_on_monitor(
-                            windows::Win32::Foundation::HWND(hwnd_raw as *mut _),
-                            target_rect,
-                            45,
+                    // The watch would pull a saved geometry or tiled windows
+                    // back to the monitor's own size, so those are placed once only.
+                    if watch_secs != Some(0) && saved_geometry.is_none() && !move_all_windows {
+                        std::thread::spawn(move || {
+                            crate::window::watch_window_on_monitor(
+                                windows::Win32::Foundation::HWND(hwnd_raw as *mut _),
+                                target_rect,
+                                watch_secs,
+                                window_placement,
+                                borderless,
+                            );
+                        });
+                    }
+
+                    if blank_other_monitors {
+                        let others: Vec<RECT> = live_monitors
+                            .iter()
+                            .filter(|m| m.device_name != device_name)
+                            .map(|m| m.rect)
+                            .collect();
+                        let status = Arc::clone(&status);
+                        let log = Arc::clone(&log);
+                        std::thread::spawn(move || {
+                            let hwnd = windows::Win32::Foundation::HWND(hwnd_raw as *mut _);
+                            let blanked = crate::window::blank_monitors_while_window(&others, hwnd);
+                            Self::push_status(
+                                &status,
+                                &log,
+                                format!(
+                                    "🖥 Blanked {blanked} other monitor(s) while the app ran; restored now."
+                                ),
+                            );
+                        });
+                        Self::push_status(
+                            &status,
+                            &log,
+                            format!(
+                                "🖥 Blanking {} other monitor(s) until the window closes.",
+                                others.len()
+                            ),
                         );
-                    });
+                    }
+
+                    // ── Launch chain ───────────────────────────────────────
+                    if let Some(next_name) = then_launch.filter(|_| placed) {
+                        if visited.contains(&next_name) {
+                            Self::push_status(
+                                &status,
+                                &log,
+                                format!(
+                                    "⚠️ Launch chain stopped: '{next_name}' already ran in this chain."
+                                ),
+                            );
+                        } else {
+                            let next = data
+                                .lock()
+                                .profiles
+                                .iter()
+                                .find(|p| p.name == next_name)
+                                .cloned();
+                            match next {
+                                Some(next) => {
+                                    Self::push_status(
+                                        &status,
+                                        &log,
+                                        format!("⛓ Chaining to '{next_name}'…"),
+                                    );
+                                    chained.extend(Self::launch_chained(
+                                        &next,
+                                        Arc::clone(&data),
+                                        visited.clone(),
+                                        Arc::clone(&status),
+                                        Arc::clone(&log),
+                                    ));
+                                }
+                                None => Self::push_status(
+                                    &status,
+                                    &log,
+                                    format!(
+                                        "⚠️ Next profile '{next_name}' not found; chain stopped."
+                                    ),
+                                ),
+                            }
+                        }
+                    }
+
+                    // ── Clear the screen around the app ────────────────────
+                    let mut minimized = vec![];
+                    if minimize_others && placed {
+                        minimized = crate::window::minimize_other_windows(&[
+                            std::process::id(),
+                            pid,
+                            window_pid,
+                        ]);
+                        Self::push_status(
+                            &status,
+                            &log,
+                            format!("🧹 Minimized {} other window(s).", minimized.len()),
+                        );
+                    }
+                    let restore_minimized = restore_others && !minimized.is_empty();
+
+                    // ── Hide DisplayWarp while the app runs ────────────────
+                    let hide_self =
+                        hide_self_on_launch && placed && !HEADLESS.load(Ordering::Relaxed);
+                    if hide_self {
+                        Self::push_status(
+                            &status,
+                            &log,
+                            format!("🙈 Hiding DisplayWarp until {exe_name} exits."),
+                        );
+                        crate::ui::hide_native_window();
+                    }
+
+                    let waited = hide_self
+                        || restore_minimized
+                        || restore_audio
+                        || post_exit_cmd.is_some()
+                        || !restores.is_empty();
+                    if waited {
+                        // A launcher may have handed off to another process;
+                        // wait on whichever process owns the placed window.
+                        if window_pid == 0 || window_pid == pid {
+                            crate::window::wait_for_process_exit(&process_handle);
+                        } else {
+                            crate::window::wait_for_pid_exit(window_pid);
+                        }
+                    }
+                    if waited {
+                        Self::forget_launched_process(&data, &profile_name, pid);
+                    }
+                    // Displays first: a monitor coming back can bring its
+                    // audio endpoint back with it.
+                    Self::restore_displays(&status, &log, &device_name, restores);
+                    if restore_audio {
+                        Self::restore_audio_devices(
+                            &status,
+                            &log,
+                            &exe_name,
+                            &restore_audio_to,
+                            restore_volume.as_ref(),
+                        );
+                    }
+                    if restore_minimized {
+                        crate::window::restore_windows(&minimized);
+                        Self::push_status(
+                            &status,
+                            &log,
+                            format!(
+                                "🪟 {exe_name} exited — restored {} window(s).",
+                                minimized.len()
+                            ),
+                        );
+                    }
+                    if hide_self {
+                        crate::tray::show_window_native();
+                        Self::push_status(
+                            &status,
+                            &log,
+                            format!("👁 {exe_name} exited — DisplayWarp restored."),
+                        );
+                    }
+                    if let Some(command) = &post_exit_cmd {
+                        Self::run_profile_command("Post-exit", command, &cwd, &status, &log);
+                    }
                 }
                 None => {
                     Self::push_status(
                         &status,
                         &log,
-                        "⚠️ Window not found within timeout. App may still have launched normally.",
+                        format!(
+                            "⚠️ Window not found within {}s. App may still have launched normally.",
+                            timeout_ms / 1000
+                        ),
                     );
+                    if restore_audio || post_exit_cmd.is_some() || !restores.is_empty() {
+                        crate::window::wait_for_process_exit(&process_handle);
+                        Self::forget_launched_process(&data, &profile_name, pid);
+                    }
+                    Self::restore_displays(&status, &log, &device_name, restores);
+                    if restore_audio {
+                        Self::restore_audio_devices(
+                            &status,
+                            &log,
+                            &exe_name,
+                            &restore_audio_to,
+                            restore_volume.as_ref(),
+                        );
+                    }
+                    if let Some(command) = &post_exit_cmd {
+                        Self::run_profile_command("Post-exit", command, &cwd, &status, &log);
+                    }
                 }
             }
 
+            drop(process_handle);
+            launch_done.finish();
+
             unsafe {
                 windows::Win32::System::Com::CoUninitialize();
             }
-        });
+            for task in chained {
+                let _ = task.join();
+            }
+        }))
     }
 
     pub fn move_live_window(
         hwnd: HWND,
         target_rect: RECT,
+        mode: WindowPlacementMode,
         status: Arc<parking_lot::Mutex<String>>,
         log: Arc<parking_lot::Mutex<Vec<String>>>,
     ) {
@@ -507,11 +2574,670 @@ impl WindowManagerApp {
             );
             return;
         }
+        let device_name = get_all_monitors()
+            .into_iter()
+            .find(|m| m.rect == target_rect)
+            .map(|m| m.device_name)
+            .unwrap_or_else(|| "unknown monitor".into());
+        Self::log_target(&status, &log, "Live move", &device_name, &target_rect, true);
         let hwnd_raw = hwnd.0 as isize;
         std::thread::spawn(move || {
             let hwnd = HWND(hwnd_raw as *mut _);
-            move_window_once(hwnd, target_rect);
-            Self::push_status(&status, &log, "✅ Window moved to target monitor.");
+            let placement = move_window_verified(hwnd, target_rect, mode);
+            if Self::report_placement(&status, &log, &placement) {
+                Self::push_status(&status, &log, "✅ Window moved to target monitor.");
+            }
+        });
+    }
+
+    /// Move every window of `exe_name` to the target monitor, tiling them
+    /// when there is more than one.
+    pub fn move_all_process_windows(
+        exe_name: String,
+        target_rect: RECT,
+        status: Arc<parking_lot::Mutex<String>>,
+        log: Arc<parking_lot::Mutex<Vec<String>>>,
+    ) {
+        let hwnds = crate::window::find_all_windows_by_process_name(&exe_name.to_lowercase());
+        match hwnds.len() {
+            0 => Self::push_status(
+                &status,
+                &log,
+                format!("❌ No windows found for {exe_name}."),
+            ),
+            1 => Self::move_live_window(
+                hwnds[0],
+                target_rect,
+                WindowPlacementMode::Auto,
+                status,
+                log,
+            ),
+            n => {
+                let raw: Vec<isize> = hwnds.iter().map(|h| h.0 as isize).collect();
+                std::thread::spawn(move || {
+                    let cells = crate::window::tile_rects(target_rect, n);
+                    let moved = raw
+                        .into_iter()
+                        .zip(cells)
+                        .filter(|&(hwnd_raw, cell)| {
+                            crate::window::place_window_at(HWND(hwnd_raw as *mut _), cell)
+                        })
+                        .count();
+                    Self::push_status(
+                        &status,
+                        &log,
+                        if moved == n {
+                            format!("✅ Moved and tiled {n} {exe_name} windows.")
+                        } else {
+                            format!(
+                                "⚠️ Moved and tiled {moved} of {n} {exe_name} windows; Windows refused the rest."
+                            )
+                        },
+                    );
+                });
+            }
+        }
+    }
+
+    /// Give the windows of a borderless profile their title bar and frame back.
+    pub fn restore_window_frame(
+        profile: &AppProfile,
+        status: &Arc<parking_lot::Mutex<String>>,
+        log: &Arc<parking_lot::Mutex<Vec<String>>>,
+    ) {
+        let proc_name = profile
+            .window_process_name
+            .clone()
+            .filter(|s| !s.is_empty())
+            .or_else(|| {
+                profile
+                    .exe_path
+                    .file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+            })
+            .unwrap_or_default()
+            .to_lowercase();
+        let restored = crate::window::find_all_windows_by_process_name(&proc_name)
+            .into_iter()
+            .filter(|&hwnd| crate::window::restore_window_frame(hwnd))
+            .count();
+        Self::push_status(
+            status,
+            log,
+            if restored == 0 {
+                format!("ℹ️ No borderless '{}' window to restore.", profile.name)
+            } else {
+                format!(
+                    "🔲 Restored the frame of {restored} '{}' window(s).",
+                    profile.name
+                )
+            },
+        );
+    }
+
+    /// Move every window on `source` (or, with `None`, every off-screen
+    /// window) to the primary monitor.
+    pub fn rescue_windows(&self, source: Option<RECT>) {
+        let Some(primary) = self
+            .monitors
+            .iter()
+            .find(|m| m.rect.left == 0 && m.rect.top == 0)
+            .map(|m| m.rect)
+        else {
+            Self::push_status(
+                &self.status_message,
+                &self.status_log,
+                "❌ No primary monitor found to rescue windows to.",
+            );
+            return;
+        };
+        if source == Some(primary) {
+            Self::push_status(
+                &self.status_message,
+                &self.status_log,
+                "⚠️ That is the primary monitor; pick another one to rescue from.",
+            );
+            return;
+        }
+
+        let stranded = crate::window::stranded_windows(source);
+        let status = Arc::clone(&self.status_message);
+        let log = Arc::clone(&self.status_log);
+        let hwnds: Vec<isize> = stranded.iter().map(|e| e.hwnd.0 as isize).collect();
+        Self::push_status(
+            &status,
+            &log,
+            format!(
+                "🛟 Moving {} window(s) to the primary monitor…",
+                hwnds.len()
+            ),
+        );
+        std::thread::spawn(move || {
+            for hwnd_raw in hwnds {
+                crate::window::move_window_once(
+                    HWND(hwnd_raw as *mut _),
+                    primary,
+                    WindowPlacementMode::Auto,
+                );
+            }
+            Self::push_status(&status, &log, "✅ Window rescue finished.");
         });
     }
+
+    /// Guided placement check: find (or launch) the profile's window, move it,
+    /// verify where it landed, and log a step-by-step report with hints.
+    pub fn diagnose_profile(
+        profile: &AppProfile,
+        data: Arc<parking_lot::Mutex<SavedData>>,
+        status: Arc<parking_lot::Mutex<String>>,
+        log: Arc<parking_lot::Mutex<Vec<String>>>,
+    ) {
+        let profile = profile.clone();
+        std::thread::spawn(move || {
+            Self::push_status(&status, &log, format!("🩺 Diagnosing '{}'…", profile.name));
+
+            // ── 1. Target monitor ──────────────────────────────────────────
+            let monitors = get_all_monitors();
+            let Some(target_rect) = Self::resolve_monitor(&monitors, &profile).map(|m| m.rect)
+            else {
+                Self::push_status(
+                    &status,
+                    &log,
+                    format!(
+                        "❌ 1/3 Target monitor '{}' is not connected. Reconnect it or pick another monitor in Edit.",
+                        profile.target_monitor_name
+                    ),
+                );
+                return;
+            };
+            Self::push_status(
+                &status,
+                &log,
+                format!(
+                    "🩺 1/3 Target monitor {} at ({}, {}) {}×{}.",
+                    profile.target_monitor_name,
+                    target_rect.left,
+                    target_rect.top,
+                    target_rect.right - target_rect.left,
+                    target_rect.bottom - target_rect.top
+                ),
+            );
+
+            // ── 2. Window ──────────────────────────────────────────────────
+            let proc_name = profile
+                .window_process_name
+                .clone()
+                .filter(|s| !s.is_empty())
+                .or_else(|| {
+                    profile
+                        .exe_path
+                        .file_name()
+                        .map(|n| n.to_string_lossy().into_owned())
+                })
+                .unwrap_or_default()
+                .to_lowercase();
+            let title_match = profile.window_title_match.as_deref();
+            let hwnd = match find_window_by_process_name(&proc_name, title_match) {
+                Some(h) => {
+                    Self::push_status(
+                        &status,
+                        &log,
+                        format!("🩺 2/3 '{proc_name}' is already running; using its window."),
+                    );
+                    h
+                }
+                None => {
+                    Self::push_status(
+                        &status,
+                        &log,
+                        format!("🩺 2/3 '{proc_name}' is not running — launching it…"),
+                    );
+                    Self::launch_profile(
+                        &profile,
+                        Arc::clone(&data),
+                        Arc::clone(&status),
+                        Arc::clone(&log),
+                    );
+                    match wait_for_window_by_name(&proc_name, title_match, 30_000) {
+                        Some(f) => {
+                            Self::push_status(
+                                &status,
+                                &log,
+                                format!(
+                                    "🩺 2/3 Window found after {:.1}s.",
+                                    f.elapsed_ms as f32 / 1000.0
+                                ),
+                            );
+                            // Let the launch's own placement finish first.
+                            std::thread::sleep(std::time::Duration::from_secs(2));
+                            f.hwnd
+                        }
+                        None => {
+                            Self::push_status(
+                                &status,
+                                &log,
+                                format!(
+                                    "❌ 2/3 No '{proc_name}' window appeared within 30s. If a launcher starts the game, set Window Process to the game's own exe name."
+                                ),
+                            );
+                            return;
+                        }
+                    }
+                }
+            };
+
+            // ── 3. Move + verify ───────────────────────────────────────────
+            let placement = move_window_verified(hwnd, target_rect, profile.placement);
+            Self::report_placement(&status, &log, &placement);
+            let summary = match placement {
+                Placement::OnTarget => {
+                    "✅ 3/3 Window is on the target monitor. Placement looks healthy.".to_string()
+                }
+                Placement::Corrected { .. } => "⚠️ 3/3 Window needed a second move. If it keeps drifting, enable Persistent Monitor.".to_string(),
+                Placement::OffTarget { elevated: true, .. } => {
+                    "❌ 3/3 The window belongs to an app running as administrator. Run DisplayWarp as administrator too.".to_string()
+                }
+                Placement::OffTarget { landed, .. } => {
+                    let fills_a_monitor = monitors.iter().any(|m| {
+                        m.rect.right - m.rect.left == landed.right - landed.left
+                            && m.rect.bottom - m.rect.top == landed.bottom - landed.top
+                    });
+                    if fills_a_monitor {
+                        "❌ 3/3 Window ignored SetWindowPos — it may be exclusive fullscreen. Try Force Primary, or switch the game to borderless windowed.".to_string()
+                    } else {
+                        "❌ 3/3 Window ignored SetWindowPos. The app may reposition itself; try Persistent Monitor or running DisplayWarp as administrator.".to_string()
+                    }
+                }
+                Placement::Gone => {
+                    "⚠️ 3/3 Window closed during diagnosis; the app may use a splash or launcher window. Set Window Process to the main game exe.".to_string()
+                }
+            };
+            Self::push_status(&status, &log, summary);
+        });
+    }
+
+    /// Clear `profile_name`'s launched process once it has exited, unless a
+    /// newer launch has replaced it.
+    fn forget_launched_process(
+        data: &Arc<parking_lot::Mutex<SavedData>>,
+        profile_name: &str,
+        pid: u32,
+    ) {
+        if let Some(p) = data
+            .lock()
+            .profiles
+            .iter_mut()
+            .find(|p| p.name == profile_name)
+            && p.launched_process
+                .is_some_and(|(launched, _)| launched == pid)
+        {
+            p.launched_process = None;
+        }
+    }
+
+    /// Undo the display changes a launch made, in reverse order of how they
+    /// were applied.
+    fn restore_displays(
+        status: &Arc<parking_lot::Mutex<String>>,
+        log: &Arc<parking_lot::Mutex<Vec<String>>>,
+        device_name: &str,
+        restores: DisplayRestores,
+    ) {
+        // Newest first: the primary switch and turning monitors off (never
+        // both) came after the mode, scale and HDR changes below.
+        if let Some(snapshot) = &restores.layout {
+            match crate::monitor::restore_monitor_layout(snapshot) {
+                Ok(()) => Self::push_status(status, log, "🖥️ Monitor layout restored."),
+                Err(e) => Self::push_status(
+                    status,
+                    log,
+                    format!("⚠️ Could not restore the monitor layout: {e}"),
+                ),
+            }
+        }
+        if let Some(topology) = &restores.topology {
+            match crate::monitor::restore_topology(topology) {
+                Ok(()) => Self::push_status(status, log, "🖥️ Other monitors turned back on."),
+                Err(e) => Self::push_status(
+                    status,
+                    log,
+                    format!("⚠️ Could not turn the other monitors back on: {e}"),
+                ),
+            }
+        }
+        if let Some(enabled) = restores.hdr {
+            let on_off = if enabled { "on" } else { "off" };
+            match crate::monitor::set_hdr(device_name, enabled) {
+                Ok(()) => Self::push_status(
+                    status,
+                    log,
+                    format!("🌈 HDR turned back {on_off} for {device_name}."),
+                ),
+                Err(e) => Self::push_status(status, log, format!("⚠️ Could not restore HDR: {e}")),
+            }
+        }
+        if let Some(percent) = restores.scale {
+            match crate::monitor::set_scale_percent(device_name, percent) {
+                Ok(()) => Self::push_status(
+                    status,
+                    log,
+                    format!("🔍 {device_name} scaling restored to {percent}%."),
+                ),
+                Err(e) => Self::push_status(
+                    status,
+                    log,
+                    format!("⚠️ Could not restore the display scaling: {e}"),
+                ),
+            }
+        }
+        if let Some(mode) = restores.mode {
+            match crate::monitor::set_display_mode(
+                device_name,
+                mode.width,
+                mode.height,
+                Some(mode.hz),
+            ) {
+                Ok(()) => Self::push_status(
+                    status,
+                    log,
+                    format!(
+                        "🖥️ {device_name} restored to {}×{}.",
+                        mode.width, mode.height
+                    ),
+                ),
+                Err(e) => Self::push_status(
+                    status,
+                    log,
+                    format!("⚠️ Could not restore the display mode: {e}"),
+                ),
+            }
+        }
+    }
+
+    /// Run a profile's pre-launch or post-exit `command` through `cmd /C`
+    /// without a console window, wait for it, and log how it ended. The shell
+    /// runs in a job object so that, after [`PROFILE_COMMAND_TIMEOUT`], the
+    /// command it started is stopped along with it.
+    fn run_profile_command(
+        what: &str,
+        command: &str,
+        cwd: &std::path::Path,
+        status: &Arc<parking_lot::Mutex<String>>,
+        log: &Arc<parking_lot::Mutex<Vec<String>>>,
+    ) {
+        use std::os::windows::io::AsRawHandle;
+        use std::os::windows::process::CommandExt;
+        use windows::Win32::Foundation::{CloseHandle, HANDLE};
+        use windows::Win32::System::JobObjects::{
+            AssignProcessToJobObject, CreateJobObjectW, TerminateJobObject,
+        };
+        use windows::Win32::System::Threading::CREATE_NO_WINDOW;
+
+        Self::push_status(status, log, format!("🧰 Running {what} command…"));
+        let mut child = match std::process::Command::new("cmd")
+            .arg("/C")
+            .raw_arg(command)
+            .current_dir(cwd)
+            .creation_flags(CREATE_NO_WINDOW.0)
+            .spawn()
+        {
+            Ok(child) => child,
+            Err(e) => {
+                Self::push_status(
+                    status,
+                    log,
+                    format!("❌ Could not start the {what} command: {e}"),
+                );
+                return;
+            }
+        };
+        // Without a job only cmd.exe could be killed, leaving the command running.
+        let job = unsafe { CreateJobObjectW(None, windows::core::PCWSTR::null()) }
+            .ok()
+            .filter(|&job| unsafe {
+                AssignProcessToJobObject(job, HANDLE(child.as_raw_handle())).is_ok()
+            });
+        let deadline = std::time::Instant::now() + PROFILE_COMMAND_TIMEOUT;
+        let msg = loop {
+            match child.try_wait() {
+                Ok(Some(exit)) if exit.success() => {
+                    break format!("🧰 {what} command finished.");
+                }
+                Ok(Some(exit)) => {
+                    break match exit.code() {
+                        Some(code) => format!("⚠️ {what} command exited with code {code}."),
+                        None => format!("⚠️ {what} command was terminated."),
+                    };
+                }
+                Ok(None) if std::time::Instant::now() < deadline => {
+                    std::thread::sleep(std::time::Duration::from_millis(100));
+                }
+                Ok(None) => {
+                    match job {
+                        Some(job) => unsafe {
+                            let _ = TerminateJobObject(job, 1);
+                        },
+                        None => {
+                            let _ = child.kill();
+                        }
+                    }
+                    break format!(
+                        "⚠️ {what} command still running after {}s; stopped it.",
+                        PROFILE_COMMAND_TIMEOUT.as_secs()
+                    );
+                }
+                Err(e) => break format!("⚠️ Could not wait for the {what} command: {e}"),
+            }
+        };
+        if let Some(job) = job {
+            unsafe {
+                let _ = CloseHandle(job);
+            }
+        }
+        Self::push_status(status, log, msg);
+    }
+
+    /// Switch back to the default audio devices and volume saved before launch.
+    fn restore_audio_devices(
+        status: &Arc<parking_lot::Mutex<String>>,
+        log: &Arc<parking_lot::Mutex<Vec<String>>>,
+        exe_name: &str,
+        device_ids: &[String],
+        volume: Option<&(Option<String>, f32)>,
+    ) {
+        // Restore the level first, while the profile's device is still default.
+        if let Some((device, level)) = volume
+            && let Err(e) = crate::audio::set_master_volume(device.as_deref(), *level)
+        {
+            Self::push_status(
+                status,
+                log,
+                format!("⚠️ Could not restore the previous volume: {e}"),
+            );
+        }
+        for device_id in device_ids {
+            if !crate::audio::device_connected(device_id) {
+                Self::push_status(
+                    status,
+                    log,
+                    "⚠️ Previous audio device not connected, keeping the current one.",
+                );
+                continue;
+            }
+            match crate::audio::set_default_audio_device(device_id) {
+                Ok(_) => Self::push_status(
+                    status,
+                    log,
+                    format!("🔊 {exe_name} exited — previous audio device restored."),
+                ),
+                Err(e) => Self::push_status(
+                    status,
+                    log,
+                    format!("⚠️ Could not restore the previous audio device: {e}"),
+                ),
+            }
+        }
+    }
+
+    /// One log line with where a window is about to be sent and whether the
+    /// rect came from live enumeration or the profile's cached copy.
+    fn log_target(
+        status: &Arc<parking_lot::Mutex<String>>,
+        log: &Arc<parking_lot::Mutex<Vec<String>>>,
+        context: &str,
+        device_name: &str,
+        rect: &RECT,
+        from_live: bool,
+    ) {
+        Self::push_status(
+            status,
+            log,
+            format!(
+                "🎯 {context}: {device_name} at ({}, {})–({}, {}) [{}]",
+                rect.left,
+                rect.top,
+                rect.right,
+                rect.bottom,
+                if from_live { "live" } else { "cached" }
+            ),
+        );
+    }
+
+    /// Log anything notable about a verified move. Returns true if the window
+    /// ended up on the target monitor.
+    fn report_placement(
+        status: &Arc<parking_lot::Mutex<String>>,
+        log: &Arc<parking_lot::Mutex<Vec<String>>>,
+        placement: &Placement,
+    ) -> bool {
+        let describe = |r: &RECT| {
+            format!(
+                "({}, {}) {}×{}",
+                r.left,
+                r.top,
+                r.right - r.left,
+                r.bottom - r.top
+            )
+        };
+        match placement {
+            Placement::OnTarget => true,
+            Placement::Corrected { missed } => {
+                Self::push_status(
+                    status,
+                    log,
+                    format!(
+                        "⚠️ Window landed off target at {} — corrected with a second move.",
+                        describe(missed)
+                    ),
+                );
+                true
+            }
+            Placement::OffTarget { landed, elevated } => {
+                Self::push_status(
+                    status,
+                    log,
+                    format!(
+                        "❌ Window still off target at {} after a corrective move.",
+                        describe(landed)
+                    ),
+                );
+                if *elevated {
+                    Self::push_status(
+                        status,
+                        log,
+                        "🛡 That app runs as administrator; DisplayWarp must run elevated to move its windows.",
+                    );
+                }
+                false
+            }
+            Placement::Gone => {
+                Self::push_status(
+                    status,
+                    log,
+                    "⚠️ Window closed before its placement could be verified.",
+                );
+                false
+            }
+        }
+    }
+}
+
+/// Write `contents` to a temp file beside `path`, then rename it over `path`,
+/// so a crash mid-write never leaves a truncated config behind.
+pub fn write_atomic(path: &std::path::Path, contents: &str) -> std::io::Result<()> {
+    use std::io::Write;
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    let tmp = std::path::PathBuf::from(tmp);
+    {
+        let mut file = std::fs::File::create(&tmp)?;
+        file.write_all(contents.as_bytes())?;
+        file.sync_all()?;
+    }
+    std::fs::rename(&tmp, path).inspect_err(|_| {
+        let _ = std::fs::remove_file(&tmp);
+    })
+}
+
+/// Split a launch-argument string on whitespace, keeping double-quoted parts
+/// (e.g. `"C:\My Saves\file.sav"`) together as one argument, without quotes.
+fn split_launch_args(args: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut has_arg = false;
+    for c in args.chars() {
+        match c {
+            '"' => {
+                in_quotes = !in_quotes;
+                has_arg = true;
+            }
+            c if c.is_whitespace() && !in_quotes => {
+                if has_arg {
+                    out.push(std::mem::take(&mut current));
+                    has_arg = false;
+                }
+            }
+            c => {
+                current.push(c);
+                has_arg = true;
+            }
+        }
+    }
+    if has_arg {
+        out.push(current);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split_launch_args;
+
+    #[test]
+    fn quoted_path_with_spaces_is_one_argument() {
+        assert_eq!(
+            split_launch_args(r#""C:\My Saves\file.sav""#),
+            [r"C:\My Saves\file.sav"]
+        );
+    }
+
+    #[test]
+    fn mixed_quoted_and_unquoted_arguments() {
+        assert_eq!(
+            split_launch_args(r#"-windowed -load "C:\My Saves\file.sav" -w 1920"#),
+            ["-windowed", "-load", r"C:\My Saves\file.sav", "-w", "1920"]
+        );
+    }
+
+    #[test]
+    fn empty_quotes_are_an_empty_argument() {
+        assert_eq!(split_launch_args(r#"-name "" -x"#), ["-name", "", "-x"]);
+    }
+
+    #[test]
+    fn surrounding_whitespace_is_ignored() {
+        assert_eq!(split_launch_args("  -a   -b \t"), ["-a", "-b"]);
+        assert!(split_launch_args("   ").is_empty());
+    }
 }
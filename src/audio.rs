@@ -1,10 +1,20 @@
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use eframe::egui;
 use windows::Win32::Devices::FunctionDiscovery::PKEY_Device_FriendlyName;
+use windows::Win32::Foundation::PROPERTYKEY;
+use windows::Win32::Media::Audio::Endpoints::IAudioEndpointVolume;
 use windows::Win32::Media::Audio::{
-    AUDCLNT_SHAREMODE_SHARED, DEVICE_STATE_ACTIVE, IAudioClient, IAudioRenderClient, IMMDevice,
-    IMMDeviceCollection, IMMDeviceEnumerator, MMDeviceEnumerator, eConsole, eRender,
+    AUDCLNT_SHAREMODE_SHARED, DEVICE_STATE, DEVICE_STATE_ACTIVE, EDataFlow, ERole, IAudioClient,
+    IAudioRenderClient, IMMDevice, IMMDeviceCollection, IMMDeviceEnumerator, IMMNotificationClient,
+    IMMNotificationClient_Impl, MMDeviceEnumerator, eCapture, eConsole, eRender,
+};
+use windows::Win32::System::Com::{
+    CLSCTX_ALL, COINIT_APARTMENTTHREADED, COINIT_MULTITHREADED, CoCreateInstance, CoInitializeEx,
+    CoTaskMemFree, CoUninitialize, STGM_READ,
 };
-use windows::Win32::System::Com::{CLSCTX_ALL, CoCreateInstance, CoTaskMemFree, STGM_READ};
-use windows::core::{GUID, HSTRING, Interface, PCWSTR, Result};
+use windows::core::{GUID, HSTRING, Interface, PCWSTR, Result, implement};
 
 // ─── IPolicyConfig COM Interface (Undocumented) ───────────────────────────────
 //
@@ -106,13 +116,22 @@ pub struct AudioDeviceInfo {
 }
 
 pub fn get_audio_output_devices() -> Result<Vec<AudioDeviceInfo>> {
+    get_audio_devices(eRender)
+}
+
+/// Active capture (microphone) devices.
+pub fn get_audio_input_devices() -> Result<Vec<AudioDeviceInfo>> {
+    get_audio_devices(eCapture)
+}
+
+fn get_audio_devices(flow: EDataFlow) -> Result<Vec<AudioDeviceInfo>> {
     let mut devices = Vec::new();
 
     unsafe {
         let enumerator: IMMDeviceEnumerator =
             CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL)?;
         let collection: IMMDeviceCollection =
-            enumerator.EnumAudioEndpoints(eRender, DEVICE_STATE_ACTIVE)?;
+            enumerator.EnumAudioEndpoints(flow, DEVICE_STATE_ACTIVE)?;
         let count = collection.GetCount()?;
 
         for i in 0..count {
@@ -133,6 +152,24 @@ pub fn get_audio_output_devices() -> Result<Vec<AudioDeviceInfo>> {
     Ok(devices)
 }
 
+/// The first id in `preferences` that is currently an active output device.
+pub fn first_present_device(preferences: &[String]) -> Result<Option<String>> {
+    let present = get_audio_output_devices()?;
+    Ok(preferences
+        .iter()
+        .find(|id| present.iter().any(|d| &d.id == *id))
+        .cloned())
+}
+
+/// Whether the output or input device `id` is currently connected and
+/// enabled. Switching to a missing one fails slowly, so check first.
+pub fn device_connected(id: &str) -> bool {
+    let listed = |devices: Result<Vec<AudioDeviceInfo>>| {
+        devices.is_ok_and(|devices| devices.iter().any(|d| d.id == id))
+    };
+    listed(get_audio_output_devices()) || listed(get_audio_input_devices())
+}
+
 unsafe fn get_device_friendly_name(device: &IMMDevice) -> Result<String> {
     use windows::Win32::System::Com::StructuredStorage::PropVariantClear;
     use windows::Win32::System::Variant::VT_LPWSTR;
@@ -169,12 +206,19 @@ unsafe fn get_device_friendly_name(device: &IMMDevice) -> Result<String> {
 
 // ─── Default Device ───────────────────────────────────────────────────────────
 
-#[allow(dead_code)]
 pub fn get_default_audio_device_id() -> Result<String> {
+    default_endpoint_id(eRender)
+}
+
+pub fn get_default_audio_input_id() -> Result<String> {
+    default_endpoint_id(eCapture)
+}
+
+fn default_endpoint_id(flow: EDataFlow) -> Result<String> {
     unsafe {
         let enumerator: IMMDeviceEnumerator =
             CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL)?;
-        let device = enumerator.GetDefaultAudioEndpoint(eRender, eConsole)?;
+        let device = enumerator.GetDefaultAudioEndpoint(flow, eConsole)?;
         let id_pwstr = device.GetId()?;
         let id = id_pwstr.to_string().unwrap_or_default();
         CoTaskMemFree(Some(id_pwstr.0 as *const _ as *mut _));
@@ -184,6 +228,8 @@ pub fn get_default_audio_device_id() -> Result<String> {
 
 // ─── Set Default Audio Device ─────────────────────────────────────────────────
 
+/// Make `device_id` the default endpoint. The id already encodes whether it
+/// is an output or a capture device, so this serves both.
 pub fn set_default_audio_device(device_id: &str) -> Result<()> {
     unsafe {
         let hstring_id = HSTRING::from(device_id);
@@ -201,9 +247,121 @@ pub fn set_default_audio_device(device_id: &str) -> Result<()> {
     Ok(())
 }
 
+// ─── Master Volume ────────────────────────────────────────────────────────────
+
+/// Endpoint volume for `device_id`, or for the default output when `None`.
+unsafe fn endpoint_volume(device_id: Option<&str>) -> Result<IAudioEndpointVolume> {
+    unsafe {
+        let enumerator: IMMDeviceEnumerator =
+            CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL)?;
+        let device = match device_id {
+            Some(id) => enumerator.GetDevice(&HSTRING::from(id))?,
+            None => enumerator.GetDefaultAudioEndpoint(eRender, eConsole)?,
+        };
+        device.Activate(CLSCTX_ALL, None)
+    }
+}
+
+/// Master volume as a 0.0–1.0 scalar.
+pub fn get_master_volume(device_id: Option<&str>) -> Result<f32> {
+    unsafe { endpoint_volume(device_id)?.GetMasterVolumeLevelScalar() }
+}
+
+/// Set the master volume to a 0.0–1.0 scalar.
+pub fn set_master_volume(device_id: Option<&str>, level: f32) -> Result<()> {
+    unsafe {
+        endpoint_volume(device_id)?
+            .SetMasterVolumeLevelScalar(level.clamp(0.0, 1.0), std::ptr::null())
+    }
+}
+
+// ─── Device hotplug ───────────────────────────────────────────────────────────
+
+/// Set when an audio endpoint is added, removed, enabled/disabled or made
+/// default; the UI thread clears it and re-enumerates devices.
+pub static AUDIO_DEVICES_CHANGED: AtomicBool = AtomicBool::new(false);
+static AUDIO_CHANGE_CTX: OnceLock<egui::Context> = OnceLock::new();
+
+/// Register an `IMMNotificationClient` on a thread of its own. Callbacks
+/// arrive on COM worker threads, so they only raise
+/// [`AUDIO_DEVICES_CHANGED`] and wake the UI.
+pub fn start_audio_device_listener(ctx: egui::Context) {
+    let _ = AUDIO_CHANGE_CTX.set(ctx);
+    std::thread::spawn(|| unsafe {
+        let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
+        let Ok(enumerator) =
+            CoCreateInstance::<_, IMMDeviceEnumerator>(&MMDeviceEnumerator, None, CLSCTX_ALL)
+        else {
+            return;
+        };
+        let client: IMMNotificationClient = DeviceChangeNotifier.into();
+        if enumerator
+            .RegisterEndpointNotificationCallback(&client)
+            .is_err()
+        {
+            return;
+        }
+        // Keep the enumerator and client alive for the life of the app.
+        loop {
+            std::thread::park();
+        }
+    });
+}
+
+fn notify_audio_devices_changed() {
+    AUDIO_DEVICES_CHANGED.store(true, Ordering::Relaxed);
+    if let Some(ctx) = AUDIO_CHANGE_CTX.get() {
+        ctx.request_repaint();
+    }
+}
+
+#[implement(IMMNotificationClient)]
+struct DeviceChangeNotifier;
+
+impl IMMNotificationClient_Impl for DeviceChangeNotifier_Impl {
+    fn OnDeviceStateChanged(&self, _device_id: &PCWSTR, _new_state: DEVICE_STATE) -> Result<()> {
+        notify_audio_devices_changed();
+        Ok(())
+    }
+
+    fn OnDeviceAdded(&self, _device_id: &PCWSTR) -> Result<()> {
+        notify_audio_devices_changed();
+        Ok(())
+    }
+
+    fn OnDeviceRemoved(&self, _device_id: &PCWSTR) -> Result<()> {
+        notify_audio_devices_changed();
+        Ok(())
+    }
+
+    fn OnDefaultDeviceChanged(
+        &self,
+        _flow: EDataFlow,
+        _role: ERole,
+        _default_device_id: &PCWSTR,
+    ) -> Result<()> {
+        notify_audio_devices_changed();
+        Ok(())
+    }
+
+    fn OnPropertyValueChanged(&self, _device_id: &PCWSTR, _key: &PROPERTYKEY) -> Result<()> {
+        Ok(())
+    }
+}
+
 // ─── Test Beep via WASAPI ─────────────────────────────────────────────────────
 
 pub fn play_test_beep(device_id: &str) -> Result<()> {
+    // Called from short-lived UI threads, which have no COM apartment yet.
+    let com = unsafe { CoInitializeEx(None, COINIT_APARTMENTTHREADED) };
+    let result = render_test_beep(device_id);
+    if com.is_ok() {
+        unsafe { CoUninitialize() };
+    }
+    result
+}
+
+fn render_test_beep(device_id: &str) -> Result<()> {
     const BEEP_DURATION_SECS: f32 = 0.4;
     const FREQ_HZ: f32 = 440.0;
     const AMPLITUDE: f32 = 0.35;
@@ -228,10 +386,18 @@ pub fn play_test_beep(device_id: &str) -> Result<()> {
         }
 
         let device = target_device.ok_or_else(|| {
-            windows::core::Error::from_hresult(windows::core::HRESULT(0x80070490_u32 as i32))
+            windows::core::Error::new(
+                windows::core::HRESULT(0x80070490_u32 as i32),
+                "the device is not connected",
+            )
         })?;
 
-        let audio_client: IAudioClient = device.Activate(CLSCTX_ALL, None)?;
+        let audio_client: IAudioClient = device.Activate(CLSCTX_ALL, None).map_err(|e| {
+            windows::core::Error::new(
+                e.code(),
+                format!("could not open the device for playback: {}", e.message()),
+            )
+        })?;
 
         let mix_format_ptr = audio_client.GetMixFormat()?;
         let mix_format = &*mix_format_ptr;
@@ -254,31 +420,45 @@ pub fn play_test_beep(device_id: &str) -> Result<()> {
         let buffer_frame_count = audio_client.GetBufferSize()?;
 
         let total_frames = (sample_rate as f32 * BEEP_DURATION_SECS) as u32;
-        let frames_to_write = total_frames.min(buffer_frame_count);
-
-        let data_ptr = render_client.GetBuffer(frames_to_write)?;
-
-        let sample_count = (frames_to_write as usize) * channels;
-        let samples = std::slice::from_raw_parts_mut(data_ptr as *mut f32, sample_count);
-
-        for frame in 0..frames_to_write as usize {
-            let t = frame as f32 / sample_rate as f32;
-            let envelope = if t > BEEP_DURATION_SECS - 0.02 {
-                ((BEEP_DURATION_SECS - t) / 0.02).clamp(0.0, 1.0)
-            } else {
-                1.0
-            };
-            let sample = (2.0 * std::f32::consts::PI * FREQ_HZ * t).sin() * AMPLITUDE * envelope;
-            for ch in 0..channels {
-                samples[frame * channels + ch] = sample;
+
+        // Top the buffer up as the device drains it until the whole beep is
+        // queued; one buffer may hold less than the full beep.
+        let mut written = 0u32;
+        while written < total_frames {
+            let padding = audio_client.GetCurrentPadding()?;
+            let frames = (buffer_frame_count - padding).min(total_frames - written);
+            if frames == 0 {
+                std::thread::sleep(std::time::Duration::from_millis(10));
+                continue;
             }
-        }
 
-        render_client.ReleaseBuffer(frames_to_write, 0)?;
+            let data_ptr = render_client.GetBuffer(frames)?;
+            let samples =
+                std::slice::from_raw_parts_mut(data_ptr as *mut f32, frames as usize * channels);
+            for (i, frame) in samples.chunks_exact_mut(channels).enumerate() {
+                let t = (written as usize + i) as f32 / sample_rate as f32;
+                let envelope = if t > BEEP_DURATION_SECS - 0.02 {
+                    ((BEEP_DURATION_SECS - t) / 0.02).clamp(0.0, 1.0)
+                } else {
+                    1.0
+                };
+                let sample =
+                    (2.0 * std::f32::consts::PI * FREQ_HZ * t).sin() * AMPLITUDE * envelope;
+                frame.fill(sample);
+            }
+            render_client.ReleaseBuffer(frames, 0)?;
 
-        audio_client.Start()?;
-        let sleep_ms = (BEEP_DURATION_SECS * 1000.0) as u64 + 50;
-        std::thread::sleep(std::time::Duration::from_millis(sleep_ms));
+            if written == 0 {
+                audio_client.Start()?;
+            }
+            written += frames;
+        }
+
+        // Let the queued tail play out before stopping.
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(1);
+        while audio_client.GetCurrentPadding()? > 0 && std::time::Instant::now() < deadline {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
         audio_client.Stop()?;
     }
 
@@ -0,0 +1,119 @@
+use std::sync::Arc;
+use std::sync::atomic::Ordering;
+
+use parking_lot::Mutex;
+
+use crate::app::{CONFIG_DIRTY, HEADLESS, WindowManagerApp};
+use crate::models::{AppProfile, SavedData};
+
+/// Profile name given as `--launch <name>` or `--launch=<name>`, if any.
+pub fn launch_arg() -> Option<String> {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--launch" {
+            return args.next();
+        }
+        if let Some(name) = arg.strip_prefix("--launch=") {
+            return Some(name.to_string());
+        }
+    }
+    None
+}
+
+/// Headless `--launch` mode for scripts, shortcuts and launchers such as
+/// Playnite: launch the named profile without opening any window, print the
+/// status log to stdout, and return the process exit code: 0 once the launch
+/// is done, 1 for an unknown profile, 2 for an unreadable config and 3 if the
+/// app could not be started.
+pub fn run_launch(name: &str) -> i32 {
+    attach_parent_console();
+    HEADLESS.store(true, Ordering::Relaxed);
+
+    let path = WindowManagerApp::get_config_path();
+    let data = match std::fs::read(&path)
+        .map_err(|e| e.to_string())
+        .and_then(|bytes| serde_json::from_slice::<SavedData>(&bytes).map_err(|e| e.to_string()))
+    {
+        Ok(data) => data,
+        Err(e) => {
+            eprintln!("❌ Could not read {}: {e}", path.display());
+            return 2;
+        }
+    };
+    let Some(profile) = data
+        .profiles
+        .iter()
+        .find(|p| p.name.eq_ignore_ascii_case(name.trim()))
+        .cloned()
+    else {
+        eprintln!("❌ No profile named '{name}'.");
+        return 1;
+    };
+
+    let before: Vec<(String, MonitorTarget)> = data
+        .profiles
+        .iter()
+        .map(|p| (p.name.clone(), monitor_target(p)))
+        .collect();
+    let data = Arc::new(Mutex::new(data));
+    let launched = WindowManagerApp::launch_profile_blocking(
+        &profile,
+        Arc::clone(&data),
+        Arc::new(Mutex::new(String::new())),
+        Arc::new(Mutex::new(Vec::new())),
+    );
+
+    // The launch may have refreshed the chain's cached monitors.
+    if CONFIG_DIRTY.swap(false, Ordering::Relaxed)
+        && let Err(e) = save_healed_monitors(&path, &data.lock(), &before)
+    {
+        eprintln!("⚠️ Could not save config: {e}");
+    }
+    if launched { 0 } else { 3 }
+}
+
+/// A profile's target monitor fields, the ones a launch may refresh.
+type MonitorTarget = (String, Option<String>, Option<[i32; 4]>);
+
+fn monitor_target(p: &AppProfile) -> MonitorTarget {
+    (
+        p.target_monitor_name.clone(),
+        p.target_monitor_id.clone(),
+        p.target_monitor_rect
+            .as_ref()
+            .map(|r| [r.left, r.top, r.right, r.bottom]),
+    )
+}
+
+/// Write the monitors the launch refreshed into the config as it is on disk
+/// now. The launch may have run for hours, and anything else the GUI saved in
+/// the meantime must survive.
+fn save_healed_monitors(
+    path: &std::path::Path,
+    data: &SavedData,
+    before: &[(String, MonitorTarget)],
+) -> Result<(), String> {
+    let bytes = std::fs::read(path).map_err(|e| e.to_string())?;
+    let mut on_disk: SavedData = serde_json::from_slice(&bytes).map_err(|e| e.to_string())?;
+    for p in &data.profiles {
+        let healed = before
+            .iter()
+            .any(|(name, old)| *name == p.name && *old != monitor_target(p));
+        if healed && let Some(d) = on_disk.profiles.iter_mut().find(|d| d.name == p.name) {
+            d.target_monitor_name = p.target_monitor_name.clone();
+            d.target_monitor_id = p.target_monitor_id.clone();
+            d.target_monitor_rect = p.target_monitor_rect.clone();
+        }
+    }
+    let json = serde_json::to_string_pretty(&on_disk).map_err(|e| e.to_string())?;
+    crate::app::write_atomic(path, &json).map_err(|e| e.to_string())
+}
+
+/// Release builds use the GUI subsystem and start without a console, so
+/// borrow the one of the shell that ran us for stdout.
+fn attach_parent_console() {
+    use windows::Win32::System::Console::{ATTACH_PARENT_PROCESS, AttachConsole};
+    unsafe {
+        let _ = AttachConsole(ATTACH_PARENT_PROCESS);
+    }
+}
@@ -0,0 +1,138 @@
+use std::sync::OnceLock;
+
+use windows::Win32::Foundation::{CloseHandle, HANDLE, HWND};
+use windows::Win32::Security::{GetTokenInformation, TOKEN_ELEVATION, TOKEN_QUERY, TokenElevation};
+use windows::Win32::System::Threading::{
+    GetCurrentProcess, GetProcessId, OpenProcess, OpenProcessToken,
+    PROCESS_QUERY_LIMITED_INFORMATION,
+};
+use windows::Win32::UI::Shell::{
+    SEE_MASK_NOCLOSEPROCESS, SHELLEXECUTEINFOW, ShellExecuteExW, ShellExecuteW,
+};
+use windows::Win32::UI::WindowsAndMessaging::{GetWindowThreadProcessId, SW_SHOWNORMAL};
+use windows::core::PCWSTR;
+
+/// True if DisplayWarp itself is running as administrator. Checked once.
+pub fn is_elevated() -> bool {
+    static ELEVATED: OnceLock<bool> = OnceLock::new();
+    *ELEVATED.get_or_init(|| unsafe { token_is_elevated(GetCurrentProcess()) }.unwrap_or(false))
+}
+
+/// Whether `process`'s token is elevated. `None` when the token can't be
+/// opened, which from a non-elevated caller usually means it is.
+unsafe fn token_is_elevated(process: HANDLE) -> Option<bool> {
+    unsafe {
+        let mut token = HANDLE::default();
+        OpenProcessToken(process, TOKEN_QUERY, &mut token).ok()?;
+        let mut elevation = TOKEN_ELEVATION::default();
+        let mut len = 0u32;
+        let res = GetTokenInformation(
+            token,
+            TokenElevation,
+            Some(&mut elevation as *mut _ as *mut _),
+            std::mem::size_of::<TOKEN_ELEVATION>() as u32,
+            &mut len,
+        );
+        let _ = CloseHandle(token);
+        res.ok()?;
+        Some(elevation.TokenIsElevated != 0)
+    }
+}
+
+/// True when `hwnd` belongs to an elevated process and DisplayWarp is not
+/// elevated, so Windows (UIPI) silently blocks moving it.
+pub fn blocked_by_elevation(hwnd: HWND) -> bool {
+    if is_elevated() {
+        return false;
+    }
+    unsafe {
+        let mut pid = 0u32;
+        GetWindowThreadProcessId(hwnd, Some(&mut pid));
+        if pid == 0 {
+            return false;
+        }
+        let Ok(hproc) = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid) else {
+            return false;
+        };
+        let elevated = token_is_elevated(hproc).unwrap_or(true);
+        let _ = CloseHandle(hproc);
+        elevated
+    }
+}
+
+/// Start a new elevated copy of DisplayWarp with the same arguments. The UAC
+/// prompt is shown by Windows; an error means it was declined or failed.
+pub fn relaunch_elevated() -> Result<(), String> {
+    let exe = std::env::current_exe().map_err(|e| e.to_string())?;
+    let params = std::env::args()
+        .skip(1)
+        .map(|a| {
+            if a.contains(' ') {
+                format!("\"{a}\"")
+            } else {
+                a
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let wide = |s: &str| -> Vec<u16> { s.encode_utf16().chain(Some(0)).collect() };
+    let verb = wide("runas");
+    let file = wide(&exe.to_string_lossy());
+    let params = wide(&params);
+    let result = unsafe {
+        ShellExecuteW(
+            None,
+            PCWSTR(verb.as_ptr()),
+            PCWSTR(file.as_ptr()),
+            PCWSTR(params.as_ptr()),
+            PCWSTR::null(),
+            SW_SHOWNORMAL,
+        )
+    };
+    // Values above 32 mean success.
+    if result.0 as isize > 32 {
+        Ok(())
+    } else {
+        Err("elevation was cancelled or failed".into())
+    }
+}
+
+/// Launch `exe` through UAC with the `runas` verb. `Command` can't request
+/// elevation, so this goes through the shell instead and returns the new
+/// process's PID and handle. An error means the prompt was declined or the
+/// launch failed.
+pub fn spawn_elevated(
+    exe: &std::path::Path,
+    args: &str,
+    cwd: &std::path::Path,
+) -> Result<(u32, std::os::windows::io::OwnedHandle), String> {
+    use std::os::windows::io::FromRawHandle;
+
+    let wide = |s: &str| -> Vec<u16> { s.encode_utf16().chain(Some(0)).collect() };
+    let verb = wide("runas");
+    let file = wide(&exe.to_string_lossy());
+    let params = wide(args);
+    let dir = wide(&cwd.to_string_lossy());
+    let mut info = SHELLEXECUTEINFOW {
+        cbSize: std::mem::size_of::<SHELLEXECUTEINFOW>() as u32,
+        fMask: SEE_MASK_NOCLOSEPROCESS,
+        lpVerb: PCWSTR(verb.as_ptr()),
+        lpFile: PCWSTR(file.as_ptr()),
+        lpParameters: PCWSTR(params.as_ptr()),
+        lpDirectory: PCWSTR(dir.as_ptr()),
+        nShow: SW_SHOWNORMAL.0,
+        ..Default::default()
+    };
+    unsafe {
+        ShellExecuteExW(&mut info).map_err(|e| e.message())?;
+        if info.hProcess.is_invalid() {
+            return Err("no process was started".into());
+        }
+        let pid = GetProcessId(info.hProcess);
+        Ok((
+            pid,
+            std::os::windows::io::OwnedHandle::from_raw_handle(info.hProcess.0 as _),
+        ))
+    }
+}
@@ -0,0 +1,65 @@
+use std::io::Write;
+use std::sync::mpsc::{Sender, channel};
+use std::thread::JoinHandle;
+
+use parking_lot::Mutex;
+
+/// Past this size the log is renamed to `displaywarp.log.1` and restarted.
+const MAX_LOG_BYTES: u64 = 1024 * 1024;
+
+static WRITER: Mutex<Option<(Sender<String>, JoinHandle<()>)>> = parking_lot::const_mutex(None);
+
+/// Append a status-log line to `displaywarp.log` in the config folder, so
+/// failures can be diagnosed after DisplayWarp is closed. Lines are queued to
+/// a dedicated writer thread; the caller never waits on disk.
+pub fn append(line: String) {
+    let mut writer = WRITER.lock();
+    let (sender, _) = writer.get_or_insert_with(|| {
+        let (tx, rx) = channel::<String>();
+        let path = crate::app::WindowManagerApp::get_config_dir().join("displaywarp.log");
+        let handle = std::thread::spawn(move || {
+            let mut file = None;
+            for line in rx {
+                if file.is_none() {
+                    rotate_if_large(&path);
+                    file = std::fs::OpenOptions::new()
+                        .create(true)
+                        .append(true)
+                        .open(&path)
+                        .ok();
+                }
+                let Some(f) = file.as_mut() else {
+                    continue;
+                };
+                if writeln!(f, "{line}").is_err() {
+                    file = None;
+                    continue;
+                }
+                if f.metadata().is_ok_and(|m| m.len() > MAX_LOG_BYTES) {
+                    // Reopened (and rotated) on the next line.
+                    file = None;
+                }
+            }
+        });
+        (tx, handle)
+    });
+    let _ = sender.send(line);
+}
+
+/// Write out every queued line and stop the writer thread. Call before
+/// `std::process::exit`, which would otherwise drop lines still in the queue.
+pub fn flush() {
+    let writer = WRITER.lock().take();
+    if let Some((sender, handle)) = writer {
+        drop(sender);
+        let _ = handle.join();
+    }
+}
+
+fn rotate_if_large(path: &std::path::Path) {
+    if std::fs::metadata(path).is_ok_and(|m| m.len() > MAX_LOG_BYTES) {
+        let mut rotated = path.as_os_str().to_owned();
+        rotated.push(".1");
+        let _ = std::fs::rename(path, rotated);
+    }
+}
@@ -0,0 +1,347 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use eframe::egui;
+use parking_lot::Mutex;
+use windows::Win32::Foundation::{LPARAM, WPARAM};
+use windows::Win32::System::Threading::GetCurrentThreadId;
+use windows::Win32::UI::Input::KeyboardAndMouse::{
+    HOT_KEY_MODIFIERS, MOD_ALT, MOD_CONTROL, MOD_NOREPEAT, MOD_SHIFT, MOD_WIN, RegisterHotKey,
+    UnregisterHotKey,
+};
+use windows::Win32::UI::WindowsAndMessaging::{
+    GetForegroundWindow, GetMessageW, MSG, PostThreadMessageW, WM_APP, WM_HOTKEY,
+};
+
+use crate::app::WindowManagerApp;
+use crate::models::{SavedData, WindowPlacementMode};
+
+/// Hotkey ids for profiles start here; id 1 is the quick-move hotkey.
+const PROFILE_HOTKEY_BASE: i32 = 100;
+/// Registered on the profile hotkey thread alongside the profile hotkeys.
+const NEXT_MONITOR_HOTKEY_ID: i32 = 2;
+/// Posted to the profile hotkey thread to re-read profiles and re-register.
+const WM_RELOAD_HOTKEYS: u32 = WM_APP + 1;
+/// Thread id of the profile hotkey loop, 0 until it has started.
+static PROFILE_HOTKEY_THREAD: AtomicU32 = AtomicU32::new(0);
+
+/// Shown in the UI next to the quick-move feature.
+pub const QUICK_MOVE_HOTKEY: &str = "Ctrl+Alt+M";
+
+/// Register the global quick-move hotkey on a dedicated message-loop thread.
+/// Each press stores the window that was in the foreground at that moment in
+/// `pending` and wakes the UI, which then opens the quick-move popup.
+pub fn start_quick_move_hotkey(
+    pending: Arc<Mutex<Option<isize>>>,
+    ctx: egui::Context,
+    status: Arc<Mutex<String>>,
+    log: Arc<Mutex<Vec<String>>>,
+) {
+    std::thread::spawn(move || unsafe {
+        // Hotkeys registered without a window post WM_HOTKEY to this thread.
+        if RegisterHotKey(
+            None,
+            1,
+            MOD_CONTROL | MOD_ALT | MOD_NOREPEAT,
+            u32::from(b'M'),
+        )
+        .is_err()
+        {
+            WindowManagerApp::push_status(
+                &status,
+                &log,
+                format!("⚠️ Could not register {QUICK_MOVE_HOTKEY}; another app may own it."),
+            );
+            return;
+        }
+
+        let mut msg = MSG::default();
+        while GetMessageW(&mut msg, None, 0, 0).as_bool() {
+            if msg.message == WM_HOTKEY {
+                *pending.lock() = Some(GetForegroundWindow().0 as isize);
+                ctx.request_repaint();
+            }
+        }
+    });
+}
+
+/// Parse a combo like "Ctrl+Alt+1" or "Shift+Win+F5" into `RegisterHotKey`
+/// modifiers and a virtual-key code. Needs at least one modifier.
+pub fn parse_hotkey(combo: &str) -> Option<(HOT_KEY_MODIFIERS, u32)> {
+    let mut mods = HOT_KEY_MODIFIERS(0);
+    let mut vk = None;
+    for part in combo.split('+').map(str::trim) {
+        match part.to_ascii_lowercase().as_str() {
+            "ctrl" | "control" => mods |= MOD_CONTROL,
+            "alt" => mods |= MOD_ALT,
+            "shift" => mods |= MOD_SHIFT,
+            "win" => mods |= MOD_WIN,
+            key => vk = Some(key_code(key)?),
+        }
+    }
+    if mods.0 == 0 {
+        return None;
+    }
+    Some((mods | MOD_NOREPEAT, vk?))
+}
+
+/// Virtual-key code for a single letter/digit or F1–F24.
+fn key_code(key: &str) -> Option<u32> {
+    let upper = key.to_ascii_uppercase();
+    match upper.as_bytes() {
+        [c] if c.is_ascii_alphanumeric() => Some(u32::from(*c)),
+        [b'F', ..] => match upper[1..].parse::<u32>() {
+            Ok(n @ 1..=24) => Some(0x70 + n - 1),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Register every profile's hotkey on a dedicated message-loop thread and
+/// launch the profile when its hotkey fires. The next-monitor hotkey lives on
+/// the same thread. Call [`reload_profile_hotkeys`] after profiles or
+/// settings change.
+pub fn start_profile_hotkeys(
+    data: Arc<Mutex<SavedData>>,
+    status: Arc<Mutex<String>>,
+    log: Arc<Mutex<Vec<String>>>,
+) {
+    std::thread::spawn(move || unsafe {
+        PROFILE_HOTKEY_THREAD.store(GetCurrentThreadId(), Ordering::Relaxed);
+        // Registration failures already shown, so reloading on every save
+        // doesn't repeat them.
+        let mut reported = Vec::new();
+        let mut registered = register_profile_hotkeys(&data, &status, &log, &[], &mut reported);
+        register_next_monitor_hotkey(&data, &status, &log, &mut reported);
+
+        let mut msg = MSG::default();
+        while GetMessageW(&mut msg, None, 0, 0).as_bool() {
+            if msg.message == WM_RELOAD_HOTKEYS {
+                registered =
+                    register_profile_hotkeys(&data, &status, &log, &registered, &mut reported);
+                register_next_monitor_hotkey(&data, &status, &log, &mut reported);
+            } else if msg.message == WM_HOTKEY {
+                let id = msg.wParam.0 as i32;
+                if id == NEXT_MONITOR_HOTKEY_ID {
+                    move_foreground_to_next_monitor(&data, &status, &log);
+                    continue;
+                }
+                let Some((_, name)) = registered.iter().find(|(rid, _)| *rid == id) else {
+                    continue;
+                };
+                let profile = data
+                    .lock()
+                    .profiles
+                    .iter()
+                    .find(|p| &p.name == name)
+                    .cloned();
+                if let Some(profile) = profile {
+                    WindowManagerApp::push_status(
+                        &status,
+                        &log,
+                        format!("⌨️ Hotkey: launching '{}'", profile.name),
+                    );
+                    WindowManagerApp::launch_profile(
+                        &profile,
+                        Arc::clone(&data),
+                        Arc::clone(&status),
+                        Arc::clone(&log),
+                    );
+                }
+            }
+        }
+    });
+}
+
+/// Ask the hotkey thread to pick up changed profile hotkeys.
+pub fn reload_profile_hotkeys() {
+    let thread = PROFILE_HOTKEY_THREAD.load(Ordering::Relaxed);
+    if thread != 0 {
+        unsafe {
+            let _ = PostThreadMessageW(thread, WM_RELOAD_HOTKEYS, WPARAM(0), LPARAM(0));
+        }
+    }
+}
+
+/// DisplayWarp's own hotkeys, which profile hotkeys can't reuse, with a
+/// description of their owner.
+fn reserved_hotkeys(data: &SavedData) -> Vec<((HOT_KEY_MODIFIERS, u32), &'static str)> {
+    [
+        (QUICK_MOVE_HOTKEY, "quick move"),
+        (data.next_monitor_hotkey.as_str(), "the next-monitor hotkey"),
+    ]
+    .into_iter()
+    .filter_map(|(combo, owner)| Some((parse_hotkey(combo)?, owner)))
+    .collect()
+}
+
+/// Why `combo` can't be used as the hotkey of profile `except` (or of a new
+/// profile): not a valid combo, or the same keys as a built-in hotkey or
+/// another profile's. `None` if it is free or blank.
+pub fn hotkey_problem(data: &SavedData, except: Option<usize>, combo: &str) -> Option<String> {
+    let combo = combo.trim();
+    if combo.is_empty() {
+        return None;
+    }
+    let Some(key) = parse_hotkey(combo) else {
+        return Some(format!(
+            "hotkey '{combo}' is not valid (needs a modifier and a key)."
+        ));
+    };
+    if let Some((_, owner)) = reserved_hotkeys(data).into_iter().find(|(k, _)| *k == key) {
+        return Some(format!("{combo} is already used by {owner}."));
+    }
+    data.profiles
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| Some(*i) != except)
+        .find(|(_, p)| p.hotkey.as_deref().and_then(parse_hotkey) == Some(key))
+        .map(|(_, p)| format!("{combo} is already used by '{}'.", p.name))
+}
+
+/// Show `msg` unless it is already in `reported`.
+fn report_once(
+    status: &Arc<Mutex<String>>,
+    log: &Arc<Mutex<Vec<String>>>,
+    reported: &mut Vec<String>,
+    msg: String,
+) {
+    if !reported.contains(&msg) {
+        WindowManagerApp::push_status(status, log, msg.clone());
+        reported.push(msg);
+    }
+}
+
+/// Drop `previous` registrations and register the current profile hotkeys.
+/// Returns (hotkey id, profile name) for each registration that succeeded.
+/// Invalid and clashing combos are skipped here; the edit form reports them
+/// on Save.
+fn register_profile_hotkeys(
+    data: &Arc<Mutex<SavedData>>,
+    status: &Arc<Mutex<String>>,
+    log: &Arc<Mutex<Vec<String>>>,
+    previous: &[(i32, String)],
+    reported: &mut Vec<String>,
+) -> Vec<(i32, String)> {
+    for (id, _) in previous {
+        let _ = unsafe { UnregisterHotKey(None, *id) };
+    }
+    let (wanted, mut taken) = {
+        let d = data.lock();
+        let wanted: Vec<(String, String)> = d
+            .profiles
+            .iter()
+            .filter_map(|p| Some((p.name.clone(), p.hotkey.clone()?)))
+            .filter(|(_, combo)| !combo.trim().is_empty())
+            .collect();
+        let taken: Vec<(HOT_KEY_MODIFIERS, u32)> =
+            reserved_hotkeys(&d).into_iter().map(|(k, _)| k).collect();
+        (wanted, taken)
+    };
+
+    let mut registered = Vec::new();
+    for (i, (name, combo)) in wanted.iter().enumerate() {
+        let Some(key) = parse_hotkey(combo).filter(|k| !taken.contains(k)) else {
+            continue;
+        };
+        let id = PROFILE_HOTKEY_BASE + i as i32;
+        if unsafe { RegisterHotKey(None, id, key.0, key.1) }.is_err() {
+            report_once(
+                status,
+                log,
+                reported,
+                format!("⚠️ '{name}': could not register {combo}; another app may own it."),
+            );
+            continue;
+        }
+        taken.push(key);
+        registered.push((id, name.clone()));
+    }
+    registered
+}
+
+/// (Re)register the next-monitor hotkey from the settings. A blank combo
+/// leaves it unregistered.
+fn register_next_monitor_hotkey(
+    data: &Arc<Mutex<SavedData>>,
+    status: &Arc<Mutex<String>>,
+    log: &Arc<Mutex<Vec<String>>>,
+    reported: &mut Vec<String>,
+) {
+    let _ = unsafe { UnregisterHotKey(None, NEXT_MONITOR_HOTKEY_ID) };
+    let combo = data.lock().next_monitor_hotkey.trim().to_string();
+    if combo.is_empty() {
+        return;
+    }
+    let Some((mods, vk)) = parse_hotkey(&combo) else {
+        report_once(
+            status,
+            log,
+            reported,
+            format!("⚠️ Next-monitor hotkey '{combo}' is not valid (needs a modifier and a key)."),
+        );
+        return;
+    };
+    if unsafe { RegisterHotKey(None, NEXT_MONITOR_HOTKEY_ID, mods, vk) }.is_err() {
+        report_once(
+            status,
+            log,
+            reported,
+            format!("⚠️ Could not register {combo}; another app or profile may own it."),
+        );
+    }
+}
+
+/// Send the foreground window to the monitor after the one it is on, in
+/// `get_all_monitors` order, wrapping around after the last.
+fn move_foreground_to_next_monitor(
+    data: &Arc<Mutex<SavedData>>,
+    status: &Arc<Mutex<String>>,
+    log: &Arc<Mutex<Vec<String>>>,
+) {
+    let hwnd = unsafe { GetForegroundWindow() };
+    // The desktop and taskbar take focus too; leave them where they are.
+    if hwnd.is_invalid() || !crate::window::is_app_window(hwnd) {
+        return;
+    }
+    let monitors = crate::monitor::get_all_monitors();
+    if monitors.len() < 2 {
+        WindowManagerApp::push_status(
+            status,
+            log,
+            "⚠️ Only one monitor is connected; nowhere to move the window.",
+        );
+        return;
+    }
+    if crate::elevation::blocked_by_elevation(hwnd) {
+        WindowManagerApp::push_status(
+            status,
+            log,
+            "⚠️ The focused window belongs to an app running as administrator; relaunch DisplayWarp as administrator to move it.",
+        );
+        return;
+    }
+
+    let current = crate::monitor::monitor_of_window(hwnd);
+    let next = match monitors
+        .iter()
+        .position(|m| Some(&m.device_name) == current.as_ref())
+    {
+        Some(i) => &monitors[(i + 1) % monitors.len()],
+        None => &monitors[0],
+    };
+    crate::window::move_window_once(hwnd, next.rect, WindowPlacementMode::Auto);
+
+    let name = data
+        .lock()
+        .monitor_label(next)
+        .cloned()
+        .or_else(|| next.hardware_name.clone())
+        .unwrap_or_else(|| next.device_name.clone());
+    WindowManagerApp::push_status(
+        status,
+        log,
+        format!("⌨️ Moved the focused window to {name}."),
+    );
+}
@@ -0,0 +1,112 @@
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+use crate::models::{AppProfile, MonitorInfo, SerializableRect};
+
+// ─── Import from other tools ─────────────────────────────────────────────────
+//
+// Accepts a minimal, tool-agnostic format so profiles can be carried over from
+// DisplayFusion and similar apps after a quick export/edit:
+//
+//   CSV:  name,exe,monitor          (header row optional, monitor is 1-based)
+//   JSON: [{ "name": "...", "exe": "C:\\...", "monitor": 2 }]
+
+#[derive(Deserialize)]
+struct ForeignProfile {
+    name: String,
+    exe: PathBuf,
+    monitor: usize,
+}
+
+/// Parse `path` (CSV or JSON, by extension) into profiles targeting the
+/// current monitors. Rows that can't be mapped are returned as messages.
+pub fn import_foreign_profiles(
+    path: &Path,
+    monitors: &[MonitorInfo],
+) -> Result<(Vec<AppProfile>, Vec<String>), String> {
+    let text = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let is_json = path
+        .extension()
+        .is_some_and(|e| e.eq_ignore_ascii_case("json"));
+
+    // Numbered as the user sees them: JSON entries from 1, CSV rows by their
+    // line in the file, header and blank lines included.
+    let rows: Vec<(usize, Result<ForeignProfile, String>)> = if is_json {
+        serde_json::from_str::<Vec<ForeignProfile>>(&text)
+            .map_err(|e| format!("invalid JSON: {e}"))?
+            .into_iter()
+            .enumerate()
+            .map(|(i, fp)| (i + 1, Ok(fp)))
+            .collect()
+    } else {
+        text.lines()
+            .enumerate()
+            .map(|(i, l)| (i + 1, l.trim()))
+            .filter(|(_, l)| !l.is_empty())
+            .filter(|(_, l)| !l.to_ascii_lowercase().starts_with("name,"))
+            .map(|(row, l)| (row, parse_csv_row(l)))
+            .collect()
+    };
+
+    let mut profiles = Vec::new();
+    let mut problems = Vec::new();
+    for (row, parsed) in rows {
+        let fp = match parsed {
+            Ok(fp) => fp,
+            Err(e) => {
+                problems.push(format!("Row {row}: {e}"));
+                continue;
+            }
+        };
+        let Some(mon) = fp.monitor.checked_sub(1).and_then(|i| monitors.get(i)) else {
+            problems.push(format!(
+                "Row {row} ('{}'): monitor {} is not connected ({} detected).",
+                fp.name,
+                fp.monitor,
+                monitors.len()
+            ));
+            continue;
+        };
+        if fp.name.trim().is_empty() {
+            problems.push(format!("Row {row}: missing name."));
+            continue;
+        }
+        profiles.push(AppProfile {
+            name: fp.name.trim().to_string(),
+            exe_path: fp.exe,
+            target_monitor_name: mon.device_name.clone(),
+            target_monitor_id: mon.stable_id.clone(),
+            target_monitor_rect: Some(SerializableRect::from(mon.rect)),
+            ..Default::default()
+        });
+    }
+    Ok((profiles, problems))
+}
+
+/// Split one `name,exe,monitor` line, honouring double-quoted fields.
+fn parse_csv_row(line: &str) -> Result<ForeignProfile, String> {
+    let mut fields = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    for c in line.chars() {
+        match c {
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut current)),
+            _ => current.push(c),
+        }
+    }
+    fields.push(current);
+
+    let [name, exe, monitor] = fields.as_slice() else {
+        return Err(format!("expected 3 columns, found {}.", fields.len()));
+    };
+    let monitor = monitor
+        .trim()
+        .parse()
+        .map_err(|_| format!("'{}' is not a monitor number.", monitor.trim()))?;
+    Ok(ForeignProfile {
+        name: name.trim().to_string(),
+        exe: PathBuf::from(exe.trim()),
+        monitor,
+    })
+}
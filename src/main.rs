@@ -2,8 +2,14 @@
 
 mod app;
 mod audio;
+mod cli;
+mod elevation;
+mod file_log;
+mod hotkey;
+mod import;
 mod models;
 mod monitor;
+mod shortcut;
 mod svg_render;
 mod tray;
 mod ui;
@@ -26,29 +32,45 @@ fn main() -> eframe::Result {
     unsafe {
         let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
     }
+
+    // `--launch "<profile>"`: run the profile headless and exit, no UI.
+    if let Some(name) = cli::launch_arg() {
+        let code = cli::run_launch(&name);
+        // `exit` skips the writer thread, so drain the log first.
+        file_log::flush();
+        std::process::exit(code);
+    }
+
     // Decode the PNG icon for the window titlebar.
-    let (icon_rgba, w, h) =
-        svg_render::png_to_rgba(include_bytes!("../assets/DisplayWarpIcon64.png"));
-    let icon = egui::IconData {
-        rgba: icon_rgba,
-        width: w,
-        height: h,
-    };
+    let icon = svg_render::app_icon_rgba().map(|(rgba, w, h)| egui::IconData {
+        rgba: rgba.clone(),
+        width: *w,
+        height: *h,
+    });
 
-    // Pre-load data to check for "Start Minimized" configuration
+    // Pre-load data to check for "Start Minimized" / "Start without focus"
     let mut start_visible = true;
+    let mut start_active = true;
     if let Ok(bytes) = std::fs::read(app::WindowManagerApp::get_config_path())
         && let Ok(decoded) = serde_json::from_slice::<models::SavedData>(&bytes)
     {
         start_visible = !decoded.start_minimized;
+        start_active = !decoded.start_without_focus;
+    }
+    if app::WindowManagerApp::minimized_flag() {
+        start_visible = false;
     }
 
+    let mut viewport = egui::ViewportBuilder::default()
+        .with_visible(start_visible)
+        .with_active(start_active)
+        .with_inner_size([980.0, 960.0])
+        .with_min_inner_size([960.0, 960.0]);
+    if let Some(icon) = icon {
+        viewport = viewport.with_icon(std::sync::Arc::new(icon));
+    }
     let options = eframe::NativeOptions {
-        viewport: egui::ViewportBuilder::default()
-            .with_visible(start_visible)
-            .with_inner_size([980.0, 960.0])
-            .with_min_inner_size([960.0, 960.0])
-            .with_icon(std::sync::Arc::new(icon)),
+        viewport,
         ..Default::default()
     };
     eframe::run_native(
@@ -104,13 +126,35 @@ fn main() -> eframe::Result {
                 "logo-light",
             );
 
-            let tray_items = tray::create_tray(
+            app.tray = tray::create_tray(
                 app.watcher_running.clone(),
                 app.data.clone(),
                 app.status_message.clone(),
                 app.status_log.clone(),
             );
-            app.tray = Some(tray_items);
+            hotkey::start_profile_hotkeys(
+                app.data.clone(),
+                app.status_message.clone(),
+                app.status_log.clone(),
+            );
+            hotkey::start_quick_move_hotkey(
+                app.quick_move_pending.clone(),
+                cc.egui_ctx.clone(),
+                app.status_message.clone(),
+                app.status_log.clone(),
+            );
+            monitor::start_display_change_listener(cc.egui_ctx.clone());
+            audio::start_audio_device_listener(cc.egui_ctx.clone());
+            app.run_health_check();
+            app::WindowManagerApp::push_status(
+                &app.status_message,
+                &app.status_log,
+                if elevation::is_elevated() {
+                    "🛡 Running as administrator."
+                } else {
+                    "🛡 Running without administrator rights; windows of elevated apps can't be moved."
+                },
+            );
             Ok(Box::new(app))
         }),
     )
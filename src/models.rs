@@ -13,6 +13,11 @@ pub struct AppProfile {
     /// Cached rect of the target monitor at save time (used as fallback)
     #[serde(default)]
     pub target_monitor_rect: Option<SerializableRect>,
+    /// Device interface path of the target monitor (includes its EDID
+    /// vendor/product code). Survives `\\.\DISPLAYn` renumbering, so it is
+    /// matched before `target_monitor_name`.
+    #[serde(default)]
+    pub target_monitor_id: Option<String>,
     /// Optional: if the launcher spawns a different process (e.g. a game),
     /// set this to that process' exe name, e.g. "Diablo IV.exe".
     /// Leave None to track the launched process itself.
@@ -33,9 +38,298 @@ pub struct AppProfile {
     /// Optional command-line arguments to pass when launching the profile.
     #[serde(default)]
     pub launch_args: Option<String>,
-    /// Optional window title wildcard matching, to capture dynamically named windows.
+    /// Working directory for the app and its commands. `None` uses the exe's
+    /// own folder.
+    #[serde(default)]
+    pub working_dir: Option<PathBuf>,
+    /// Shell command (run through `cmd /C`) to finish before the app starts,
+    /// e.g. a script that sets up USB devices.
+    #[serde(default)]
+    pub pre_launch_cmd: Option<String>,
+    /// Shell command to run once the app has exited.
+    #[serde(default)]
+    pub post_exit_cmd: Option<String>,
+    /// Optional window title match, to pick the right window when a shared
+    /// launcher process hosts several. `*` is a wildcard; without one the
+    /// text matches as a case-insensitive substring.
     #[serde(default)]
     pub window_title_match: Option<String>,
+    /// By default launched apps are detached from DisplayWarp (own process group,
+    /// no inherited console) so closing DisplayWarp never takes them down.
+    /// Set this to keep the child attached instead.
+    #[serde(default)]
+    pub keep_attached: bool,
+    /// Launch through UAC (`runas`), e.g. for games whose anti-cheat needs
+    /// elevation. The window is then matched by process name.
+    #[serde(default)]
+    pub run_as_admin: bool,
+    /// Move every top-level window of the process (browsers, Electron apps),
+    /// tiled on the target monitor, instead of just the main one.
+    #[serde(default)]
+    pub move_all_windows: bool,
+    /// Keep the window above others, e.g. a reference app over a fullscreen
+    /// game. Re-applied by the persistent watcher if the app drops it.
+    #[serde(default)]
+    pub always_on_top: bool,
+    /// Cover every other monitor with a black window while the app's window is
+    /// open. A per-monitor alternative to `SC_MONITORPOWER`, which can only
+    /// power down all displays at once.
+    #[serde(default)]
+    pub blank_other_monitors: bool,
+    /// Ignore the saved monitor and launch onto whichever monitor the mouse
+    /// cursor is on at launch time.
+    #[serde(default)]
+    pub follow_cursor: bool,
+    /// Name of another profile to launch once this one's window is placed.
+    #[serde(default)]
+    pub then_launch: Option<String>,
+    /// Audio devices to fall back to, in order, when `target_audio_device_id`
+    /// is not connected.
+    #[serde(default)]
+    pub audio_device_preferences: Vec<String>,
+    /// While set and in the future, the watcher leaves this profile's window
+    /// alone. Runtime only; never saved.
+    #[serde(skip)]
+    pub enforce_paused_until: Option<std::time::Instant>,
+    /// PID and start time of the process DisplayWarp last launched for this
+    /// profile, so the watcher can tell it apart from unrelated processes with
+    /// the same exe name, and from a later process that reuses the PID.
+    /// Cleared once the launch sees it exit. Runtime only; never saved.
+    #[serde(skip)]
+    pub launched_process: Option<(u32, u64)>,
+    /// Signalled when the last launch of this profile has finished its
+    /// exit-time restores. Runtime only; never saved.
+    #[serde(skip)]
+    pub launch_done: Option<LaunchDone>,
+    /// Whether the watcher found this profile's window on its last tick, for
+    /// the running dot on the profile card. Runtime only; never saved.
+    #[serde(skip)]
+    pub is_running: bool,
+    /// File whose appearance signals the game is ready (e.g. a lock file
+    /// written once anti-cheat has started). Polled before the window search.
+    #[serde(default)]
+    pub wait_for_path: Option<PathBuf>,
+    /// How long to look for the window after launch. `None` keeps the
+    /// defaults: 30 s when matching by process name, 15 s by PID.
+    #[serde(default)]
+    pub window_timeout_secs: Option<u64>,
+    /// Hide DisplayWarp to the tray once the window is placed and bring it
+    /// back when the app exits.
+    #[serde(default)]
+    pub hide_self_on_launch: bool,
+    /// Pause after switching audio so the audio graph settles before the
+    /// game opens its output device.
+    #[serde(default)]
+    pub audio_settle_ms: u32,
+    /// Minimize every other visible window once the app's window is placed.
+    #[serde(default)]
+    pub minimize_others_on_launch: bool,
+    /// Restore the windows minimized by `minimize_others_on_launch` when the
+    /// app exits.
+    #[serde(default)]
+    pub restore_others_on_exit: bool,
+    /// What happens after the first placement: nothing, a timed watch, or a
+    /// watch that lasts until the window closes.
+    #[serde(default)]
+    pub launch_watch: LaunchWatch,
+    /// Length of the `LaunchWatch::Timed` watch.
+    #[serde(default = "default_launch_watch_secs")]
+    pub launch_watch_secs: u64,
+    /// Global shortcut that launches this profile, e.g. "Ctrl+Alt+1".
+    #[serde(default)]
+    pub hotkey: Option<String>,
+    /// Put the previous default audio device back when the app exits.
+    #[serde(default = "default_true")]
+    pub restore_audio_on_exit: bool,
+    /// Resolution to switch the target monitor to before launching; the
+    /// original mode is restored when the app exits.
+    #[serde(default)]
+    pub target_resolution: Option<(u32, u32)>,
+    /// Refresh rate to use with `target_resolution`.
+    #[serde(default)]
+    pub target_refresh_hz: Option<u32>,
+    /// Windows display scaling (e.g. 150 for 150%) to set on the target
+    /// monitor at launch, restored on exit like `target_resolution`.
+    #[serde(default)]
+    pub target_scale_percent: Option<u32>,
+    /// Turn Windows HDR on (`Some(true)`) or off for the target monitor at
+    /// launch, restored on exit.
+    #[serde(default)]
+    pub enable_hdr: Option<bool>,
+    /// Turn off every monitor except the target while the app runs, and turn
+    /// them back on when it exits.
+    #[serde(default)]
+    pub disable_other_monitors: bool,
+    /// Size and position of the window within the target monitor.
+    #[serde(default)]
+    pub placement: WindowPlacementMode,
+    /// Strip the window's title bar and frame and stretch it over the whole
+    /// target monitor, for games without a borderless mode of their own.
+    #[serde(default)]
+    pub borderless_fullscreen: bool,
+    /// Exact restored-window rect captured from a running window (workspace
+    /// coordinates, as `GetWindowPlacement` reports them). When set it is
+    /// applied instead of `placement`.
+    #[serde(default)]
+    pub saved_placement: Option<SerializableRect>,
+    /// Whether the captured window was maximized.
+    #[serde(default)]
+    pub saved_maximized: bool,
+    /// Optional: capture (microphone) device to make default on launch.
+    #[serde(default)]
+    pub target_audio_input_id: Option<String>,
+    /// Optional: master volume (0.0–1.0) to set on the output device at launch.
+    #[serde(default)]
+    pub target_volume: Option<f32>,
+}
+
+impl Default for AppProfile {
+    /// A profile with every option off, as the serde defaults would load it.
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            exe_path: PathBuf::new(),
+            target_monitor_name: String::new(),
+            target_monitor_rect: None,
+            target_monitor_id: None,
+            window_process_name: None,
+            force_primary: false,
+            persistent_monitor: false,
+            target_audio_device_id: None,
+            launch_args: None,
+            working_dir: None,
+            pre_launch_cmd: None,
+            post_exit_cmd: None,
+            window_title_match: None,
+            keep_attached: false,
+            run_as_admin: false,
+            move_all_windows: false,
+            always_on_top: false,
+            blank_other_monitors: false,
+            follow_cursor: false,
+            then_launch: None,
+            audio_device_preferences: Vec::new(),
+            enforce_paused_until: None,
+            launched_process: None,
+            launch_done: None,
+            is_running: false,
+            wait_for_path: None,
+            window_timeout_secs: None,
+            hide_self_on_launch: false,
+            audio_settle_ms: 0,
+            minimize_others_on_launch: false,
+            restore_others_on_exit: false,
+            launch_watch: LaunchWatch::default(),
+            launch_watch_secs: default_launch_watch_secs(),
+            hotkey: None,
+            restore_audio_on_exit: default_true(),
+            target_resolution: None,
+            target_refresh_hz: None,
+            target_scale_percent: None,
+            enable_hdr: None,
+            disable_other_monitors: false,
+            placement: WindowPlacementMode::default(),
+            borderless_fullscreen: false,
+            saved_placement: None,
+            saved_maximized: false,
+            target_audio_input_id: None,
+            target_volume: None,
+        }
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// One-shot flag a launch thread sets once its exit-time restores are done,
+/// so a relaunch doesn't race them.
+#[derive(Clone, Debug, Default)]
+pub struct LaunchDone(std::sync::Arc<(parking_lot::Mutex<bool>, parking_lot::Condvar)>);
+
+impl LaunchDone {
+    pub fn finish(&self) {
+        let (done, cvar) = &*self.0;
+        *done.lock() = true;
+        cvar.notify_all();
+    }
+
+    /// Wait up to `timeout` for [`finish`](Self::finish). Returns whether it
+    /// was called.
+    pub fn wait(&self, timeout: std::time::Duration) -> bool {
+        let (done, cvar) = &*self.0;
+        let mut done = done.lock();
+        if !*done {
+            let _ = cvar.wait_while_for(&mut done, |done| !*done, timeout);
+        }
+        *done
+    }
+}
+
+/// Post-launch keep-alive behavior, see [`AppProfile::launch_watch`].
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug, Default)]
+pub enum LaunchWatch {
+    /// Place the window once and leave it alone.
+    OneShot,
+    /// Nudge the window back for `launch_watch_secs` seconds.
+    #[default]
+    Timed,
+    /// Nudge the window back for as long as it exists.
+    UntilClosed,
+}
+
+/// Where a moved window ends up on its monitor, see [`AppProfile::placement`].
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug, Default)]
+pub enum WindowPlacementMode {
+    /// Fill the monitor, staying maximized if the window already was.
+    #[default]
+    Auto,
+    Maximize,
+    /// Exactly the monitor rect, not maximized. For apps that misbehave
+    /// when forced into the maximized state.
+    Fill,
+    /// Keep the window's current size, centered on the monitor.
+    Centered,
+    LeftHalf,
+    RightHalf,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl WindowPlacementMode {
+    pub const ALL: [Self; 10] = [
+        Self::Auto,
+        Self::Maximize,
+        Self::Fill,
+        Self::Centered,
+        Self::LeftHalf,
+        Self::RightHalf,
+        Self::TopLeft,
+        Self::TopRight,
+        Self::BottomLeft,
+        Self::BottomRight,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Auto => "Auto",
+            Self::Maximize => "Maximize",
+            Self::Fill => "Fill exactly",
+            Self::Centered => "Centered, keep size",
+            Self::LeftHalf => "Left half",
+            Self::RightHalf => "Right half",
+            Self::TopLeft => "Top-left quarter",
+            Self::TopRight => "Top-right quarter",
+            Self::BottomLeft => "Bottom-left quarter",
+            Self::BottomRight => "Bottom-right quarter",
+        }
+    }
+}
+
+fn default_launch_watch_secs() -> u64 {
+    45
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, Default)]
@@ -78,16 +372,78 @@ impl From<windows::Win32::Foundation::RECT> for SerializableRect {
 #[derive(Serialize, Deserialize)]
 pub struct SavedData {
     pub profiles: Vec<AppProfile>,
-    #[serde(default)]
+    /// Setting replaced by `close_action`; read from old configs only so
+    /// `load_data` can migrate it.
+    #[serde(default, skip_serializing)]
     pub close_to_tray: bool,
     #[serde(default)]
+    pub close_action: CloseAction,
+    #[serde(default)]
     pub start_minimized: bool,
     #[serde(default)]
     pub start_on_boot: bool,
+    /// Open the main window without activating it, so startup at login does
+    /// not steal focus.
+    #[serde(default)]
+    pub start_without_focus: bool,
     #[serde(default = "default_watcher_interval")]
     pub watcher_interval_secs: u64,
     #[serde(default)]
     pub display_profiles: Vec<SavedDisplayLayout>,
+    #[serde(default)]
+    pub theme: ThemeMode,
+    /// Stronger text/background separation on top of the light or dark theme.
+    #[serde(default)]
+    pub high_contrast: bool,
+    /// Multiplier applied to every egui text style.
+    #[serde(default = "default_font_scale")]
+    pub font_scale: f32,
+    /// Friendly monitor names ("Living Room TV", "Vertical") keyed by
+    /// [`MonitorInfo::label_key`]. Older configs keyed them by device name.
+    #[serde(default)]
+    pub monitor_labels: std::collections::HashMap<String, String>,
+    /// Audio output to switch to when the foreground window moves onto a
+    /// monitor, keyed by [`MonitorInfo::label_key`]. Independent of profiles.
+    #[serde(default)]
+    pub monitor_audio: std::collections::HashMap<String, String>,
+    /// Global hotkey that sends the focused window to the next monitor.
+    /// Empty (the default) disables it.
+    #[serde(default)]
+    pub next_monitor_hotkey: String,
+    /// The user has accepted the warning shown the first time a profile is
+    /// set to turn off other monitors.
+    #[serde(default)]
+    pub disable_monitors_confirmed: bool,
+}
+
+/// Persisted theme choice. `Auto` follows the Windows app theme.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug, Default)]
+pub enum ThemeMode {
+    #[default]
+    Dark,
+    Light,
+    Auto,
+}
+
+/// What closing the main window does.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug, Default)]
+pub enum CloseAction {
+    #[default]
+    AskEveryTime,
+    MinimizeToTray,
+    Quit,
+}
+
+impl CloseAction {
+    pub const ALL: [Self; 3] = [Self::AskEveryTime, Self::MinimizeToTray, Self::Quit];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::AskEveryTime => "Ask every time",
+            Self::MinimizeToTray => "Minimize to tray",
+            Self::Quit => "Quit",
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -96,15 +452,67 @@ pub struct SavedDisplayLayout {
     pub monitors: Vec<SavedMonitorPos>,
 }
 
+impl SavedData {
+    /// The user's name for `m`, if any.
+    pub fn monitor_label(&self, m: &MonitorInfo) -> Option<&String> {
+        self.monitor_labels
+            .get(m.label_key())
+            .or_else(|| self.monitor_labels.get(&m.device_name))
+    }
+
+    /// Name `m`, replacing any label stored under its device name. A blank
+    /// label removes it.
+    pub fn set_monitor_label(&mut self, m: &MonitorInfo, label: &str) {
+        self.monitor_labels.remove(&m.device_name);
+        let label = label.trim();
+        if label.is_empty() {
+            self.monitor_labels.remove(m.label_key());
+        } else {
+            self.monitor_labels
+                .insert(m.label_key().to_string(), label.to_string());
+        }
+    }
+
+    /// Whether a profile other than `except` is already called `name`.
+    pub fn profile_name_taken(&self, name: &str, except: Option<usize>) -> bool {
+        self.profiles
+            .iter()
+            .enumerate()
+            .any(|(i, p)| Some(i) != except && p.name == name)
+    }
+
+    /// Rename profile `idx`, pointing every `then_launch` chain at the new name.
+    pub fn rename_profile(&mut self, idx: usize, name: String) {
+        let old = std::mem::replace(&mut self.profiles[idx].name, name.clone());
+        if old == name {
+            return;
+        }
+        for p in &mut self.profiles {
+            if p.then_launch.as_deref() == Some(old.as_str()) {
+                p.then_launch = Some(name.clone());
+            }
+        }
+    }
+}
+
 impl Default for SavedData {
     fn default() -> Self {
         Self {
             profiles: vec![],
             close_to_tray: false,
+            close_action: CloseAction::default(),
             start_minimized: false,
             start_on_boot: false,
+            start_without_focus: false,
             watcher_interval_secs: 3,
             display_profiles: vec![],
+            theme: ThemeMode::default(),
+            high_contrast: false,
+            font_scale: 1.0,
+            monitor_labels: Default::default(),
+            monitor_audio: Default::default(),
+            next_monitor_hotkey: String::new(),
+            disable_monitors_confirmed: false,
         }
     }
 }
@@ -113,6 +521,29 @@ fn default_watcher_interval() -> u64 {
     3
 }
 
+fn default_font_scale() -> f32 {
+    1.0
+}
+
+pub const DEFAULT_WORKSPACE: &str = "Default";
+
+/// Index of named workspaces, stored as `workspaces.json` in the config dir.
+/// Each workspace is a separate `SavedData` file.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct WorkspaceIndex {
+    pub active: String,
+    pub workspaces: Vec<String>,
+}
+
+impl Default for WorkspaceIndex {
+    fn default() -> Self {
+        Self {
+            active: DEFAULT_WORKSPACE.to_string(),
+            workspaces: vec![DEFAULT_WORKSPACE.to_string()],
+        }
+    }
+}
+
 // ─── Runtime State ───────────────────────────────────────────────────────────
 
 #[derive(Clone, Debug, Default)]
@@ -122,6 +553,17 @@ pub struct MonitorInfo {
     pub hardware_name: Option<String>,
     pub target_id: Option<u32>,
     pub is_active: bool,
+    /// Device interface path from `EnumDisplayDevicesW`, stable across
+    /// reboots and replugs unlike `device_name`.
+    pub stable_id: Option<String>,
+}
+
+impl MonitorInfo {
+    /// Key for `SavedData::monitor_labels`: the stable id, or the device name
+    /// when Windows doesn't report one.
+    pub fn label_key(&self) -> &str {
+        self.stable_id.as_deref().unwrap_or(&self.device_name)
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -129,4 +571,8 @@ pub struct MonitorInfo {
 pub struct SavedMonitorPos {
     pub device_name: String,
     pub rect: SerializableRect,
+    /// Resolution and refresh rate to restore along with the position.
+    /// Only display layouts capture this; undo snapshots leave it `None`.
+    #[serde(default)]
+    pub mode: Option<crate::monitor::DisplayMode>,
 }
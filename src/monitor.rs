@@ -1,9 +1,14 @@
 use std::ptr;
-use windows::Win32::Foundation::{LPARAM, RECT};
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use eframe::egui;
+use windows::Win32::Foundation::{HWND, LPARAM, LRESULT, RECT, WPARAM};
 use windows::Win32::Graphics::Gdi::{
-    CDS_GLOBAL, CDS_NORESET, CDS_SET_PRIMARY, CDS_TYPE, CDS_UPDATEREGISTRY,
-    ChangeDisplaySettingsExW, DEVMODEW, DM_POSITION, ENUM_CURRENT_SETTINGS, EnumDisplayMonitors,
-    EnumDisplaySettingsW, GetMonitorInfoW, HDC, HMONITOR, MONITORINFOEXW,
+    CDS_GLOBAL, CDS_NORESET, CDS_SET_PRIMARY, CDS_TEST, CDS_TYPE, CDS_UPDATEREGISTRY,
+    ChangeDisplaySettingsExW, DEVMODEW, DISP_CHANGE_SUCCESSFUL, DM_DISPLAYFREQUENCY, DM_PELSHEIGHT,
+    DM_PELSWIDTH, DM_POSITION, ENUM_CURRENT_SETTINGS, ENUM_DISPLAY_SETTINGS_MODE,
+    EnumDisplayMonitors, EnumDisplaySettingsW, GetMonitorInfoW, HDC, HMONITOR, MONITORINFOEXW,
 };
 use windows::core::BOOL;
 use windows::core::PCWSTR;
@@ -16,6 +21,119 @@ use windows::Win32::Devices::Display::{
     QDC_ONLY_ACTIVE_PATHS, QueryDisplayConfig,
 };
 
+/// Set when Windows reports a display change; the UI thread clears it and
+/// re-enumerates monitors.
+pub static DISPLAY_CHANGED: AtomicBool = AtomicBool::new(false);
+static DISPLAY_CHANGE_CTX: OnceLock<egui::Context> = OnceLock::new();
+
+/// Listen for `WM_DISPLAYCHANGE` (monitor plugged, unplugged or re-moded) on
+/// a hidden window of its own thread. The callback only raises
+/// [`DISPLAY_CHANGED`] and wakes the UI; it never touches app state.
+pub fn start_display_change_listener(ctx: egui::Context) {
+    use windows::Win32::System::LibraryLoader::GetModuleHandleW;
+    use windows::Win32::UI::WindowsAndMessaging::{
+        CreateWindowExW, DispatchMessageW, GetMessageW, MSG, RegisterClassW, WINDOW_EX_STYLE,
+        WINDOW_STYLE, WNDCLASSW,
+    };
+    use windows::core::w;
+
+    let _ = DISPLAY_CHANGE_CTX.set(ctx);
+    std::thread::spawn(|| unsafe {
+        let instance = GetModuleHandleW(None).unwrap_or_default();
+        let class = w!("DisplayWarpDisplayListener");
+        let wc = WNDCLASSW {
+            lpfnWndProc: Some(display_change_wndproc),
+            hInstance: instance.into(),
+            lpszClassName: class,
+            ..Default::default()
+        };
+        RegisterClassW(&wc);
+        // A plain (never shown) top-level window: message-only windows don't
+        // receive broadcasts such as WM_DISPLAYCHANGE.
+        if CreateWindowExW(
+            WINDOW_EX_STYLE(0),
+            class,
+            w!(""),
+            WINDOW_STYLE(0),
+            0,
+            0,
+            0,
+            0,
+            None,
+            None,
+            Some(instance.into()),
+            None,
+        )
+        .is_err()
+        {
+            return;
+        }
+        let mut msg = MSG::default();
+        while GetMessageW(&mut msg, None, 0, 0).as_bool() {
+            DispatchMessageW(&msg);
+        }
+    });
+}
+
+unsafe extern "system" fn display_change_wndproc(
+    hwnd: HWND,
+    msg: u32,
+    wparam: WPARAM,
+    lparam: LPARAM,
+) -> LRESULT {
+    use windows::Win32::UI::WindowsAndMessaging::{DefWindowProcW, WM_DISPLAYCHANGE};
+
+    if msg == WM_DISPLAYCHANGE {
+        notify_display_changed();
+    }
+    unsafe { DefWindowProcW(hwnd, msg, wparam, lparam) }
+}
+
+/// Raise [`DISPLAY_CHANGED`] and wake the UI, as a `WM_DISPLAYCHANGE` would.
+/// For changes Windows may not broadcast, e.g. a layout it rejected.
+pub fn notify_display_changed() {
+    DISPLAY_CHANGED.store(true, Ordering::Relaxed);
+    if let Some(ctx) = DISPLAY_CHANGE_CTX.get() {
+        ctx.request_repaint();
+    }
+}
+
+/// Device name (e.g. `\\.\DISPLAY2`) of the monitor under the mouse cursor,
+/// or the one nearest to it.
+pub fn monitor_under_cursor() -> Option<String> {
+    use windows::Win32::Foundation::POINT;
+    use windows::Win32::Graphics::Gdi::{MONITOR_DEFAULTTONEAREST, MonitorFromPoint};
+    use windows::Win32::UI::WindowsAndMessaging::GetCursorPos;
+
+    unsafe {
+        let mut pt = POINT::default();
+        GetCursorPos(&mut pt).ok()?;
+        monitor_device_name(MonitorFromPoint(pt, MONITOR_DEFAULTTONEAREST))
+    }
+}
+
+/// Device name of the monitor showing most of `hwnd`, or the one nearest to it.
+pub fn monitor_of_window(hwnd: HWND) -> Option<String> {
+    use windows::Win32::Graphics::Gdi::{MONITOR_DEFAULTTONEAREST, MonitorFromWindow};
+
+    monitor_device_name(unsafe { MonitorFromWindow(hwnd, MONITOR_DEFAULTTONEAREST) })
+}
+
+fn monitor_device_name(hmon: HMONITOR) -> Option<String> {
+    unsafe {
+        let mut info = MONITORINFOEXW::default();
+        info.monitorInfo.cbSize = std::mem::size_of::<MONITORINFOEXW>() as u32;
+        if !GetMonitorInfoW(hmon, &mut info.monitorInfo).as_bool() {
+            return None;
+        }
+        Some(
+            String::from_utf16_lossy(&info.szDevice)
+                .trim_matches(char::from(0))
+                .to_string(),
+        )
+    }
+}
+
 /// Enumerate all connected monitors and return their info.
 pub fn get_all_monitors() -> Vec<MonitorInfo> {
     let mut monitors: Vec<MonitorInfo> = Vec::new();
@@ -76,7 +194,11 @@ pub fn get_all_monitors() -> Vec<MonitorInfo> {
 }
 
 use windows::Win32::Devices::Display::{
-    DISPLAYCONFIG_DEVICE_INFO_GET_SOURCE_NAME, DISPLAYCONFIG_SOURCE_DEVICE_NAME, QDC_ALL_PATHS,
+    DISPLAYCONFIG_DEVICE_INFO_GET_ADVANCED_COLOR_INFO, DISPLAYCONFIG_DEVICE_INFO_GET_SOURCE_NAME,
+    DISPLAYCONFIG_DEVICE_INFO_HEADER, DISPLAYCONFIG_DEVICE_INFO_SET_ADVANCED_COLOR_STATE,
+    DISPLAYCONFIG_DEVICE_INFO_TYPE, DISPLAYCONFIG_GET_ADVANCED_COLOR_INFO,
+    DISPLAYCONFIG_SET_ADVANCED_COLOR_STATE, DISPLAYCONFIG_SOURCE_DEVICE_NAME,
+    DisplayConfigSetDeviceInfo, QDC_ALL_PATHS,
 };
 
 pub fn get_all_display_targets() -> Vec<MonitorInfo> {
@@ -168,6 +290,7 @@ pub fn get_all_display_targets() -> Vec<MonitorInfo> {
                 },
                 target_id: Some(target_id),
                 is_active,
+                stable_id: None,
             });
         }
     }
@@ -191,21 +314,52 @@ unsafe extern "system" fn enum_monitor_callback(
             let device_name = String::from_utf16_lossy(&info.szDevice)
                 .trim_matches(char::from(0))
                 .to_string();
+            let stable_id = monitor_interface_id(&device_name);
             monitors.push(MonitorInfo {
                 rect: info.monitorInfo.rcMonitor,
                 device_name,
                 hardware_name: None,
                 target_id: None,
                 is_active: true,
+                stable_id,
             });
         }
         BOOL(1)
     }
 }
 
+/// Device interface path of the first monitor attached to the display
+/// adapter output `device_name`, e.g.
+/// `\\?\DISPLAY#DEL4141#5&1a2b3c&0&UID4353#{e6f07b5f-...}`.
+fn monitor_interface_id(device_name: &str) -> Option<String> {
+    use windows::Win32::Graphics::Gdi::{DISPLAY_DEVICEW, EnumDisplayDevicesW};
+    use windows::Win32::UI::WindowsAndMessaging::EDD_GET_DEVICE_INTERFACE_NAME;
+
+    let name_u16: Vec<u16> = device_name.encode_utf16().chain(Some(0)).collect();
+    let mut dd = DISPLAY_DEVICEW {
+        cb: std::mem::size_of::<DISPLAY_DEVICEW>() as u32,
+        ..Default::default()
+    };
+    unsafe {
+        if !EnumDisplayDevicesW(
+            PCWSTR(name_u16.as_ptr()),
+            0,
+            &mut dd,
+            EDD_GET_DEVICE_INTERFACE_NAME,
+        )
+        .as_bool()
+        {
+            return None;
+        }
+    }
+    let id = String::from_utf16_lossy(&dd.DeviceID)
+        .trim_matches(char::from(0))
+        .to_string();
+    (!id.is_empty()).then_some(id)
+}
+
 /// Make `target_device_name` the primary monitor by shifting all monitor
 /// coordinates so the target sits at (0, 0).
-#[allow(dead_code)]
 pub fn switch_primary_to(target_device_name: &str, monitors: &[MonitorInfo]) -> bool {
     let target = match monitors
         .iter()
@@ -253,9 +407,332 @@ pub fn switch_primary_to(target_device_name: &str, monitors: &[MonitorInfo]) ->
     true
 }
 
-/// Restore monitor positions from a saved snapshot.
-#[allow(dead_code)]
-pub fn restore_monitor_layout(snapshot: &[SavedMonitorPos]) {
+/// A resolution + refresh-rate combination a monitor supports.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, serde::Serialize, serde::Deserialize)]
+pub struct DisplayMode {
+    pub width: u32,
+    pub height: u32,
+    pub hz: u32,
+}
+
+/// Every mode the monitor reports, largest first, without duplicates that
+/// differ only in color depth or scaling.
+pub fn list_display_modes(device_name: &str) -> Vec<DisplayMode> {
+    let name_u16: Vec<u16> = device_name.encode_utf16().chain(Some(0)).collect();
+    let mut modes = Vec::new();
+    unsafe {
+        let mut i = 0;
+        loop {
+            let mut dev_mode = std::mem::zeroed::<DEVMODEW>();
+            dev_mode.dmSize = std::mem::size_of::<DEVMODEW>() as u16;
+            if !EnumDisplaySettingsW(
+                PCWSTR(name_u16.as_ptr()),
+                ENUM_DISPLAY_SETTINGS_MODE(i),
+                &mut dev_mode,
+            )
+            .as_bool()
+            {
+                break;
+            }
+            let mode = DisplayMode {
+                width: dev_mode.dmPelsWidth,
+                height: dev_mode.dmPelsHeight,
+                hz: dev_mode.dmDisplayFrequency,
+            };
+            if !modes.contains(&mode) {
+                modes.push(mode);
+            }
+            i += 1;
+        }
+    }
+    modes.sort_by_key(|m| std::cmp::Reverse((m.width * m.height, m.hz)));
+    modes
+}
+
+/// The monitor's current mode.
+pub fn current_display_mode(device_name: &str) -> Option<DisplayMode> {
+    let name_u16: Vec<u16> = device_name.encode_utf16().chain(Some(0)).collect();
+    unsafe {
+        let mut dev_mode = std::mem::zeroed::<DEVMODEW>();
+        dev_mode.dmSize = std::mem::size_of::<DEVMODEW>() as u16;
+        EnumDisplaySettingsW(
+            PCWSTR(name_u16.as_ptr()),
+            ENUM_CURRENT_SETTINGS,
+            &mut dev_mode,
+        )
+        .as_bool()
+        .then_some(DisplayMode {
+            width: dev_mode.dmPelsWidth,
+            height: dev_mode.dmPelsHeight,
+            hz: dev_mode.dmDisplayFrequency,
+        })
+    }
+}
+
+/// Switch a monitor to `width`×`height` (and `hz`, if given) for this session
+/// only. The mode is tested first so unsupported modes fail without flicker.
+pub fn set_display_mode(
+    device_name: &str,
+    width: u32,
+    height: u32,
+    hz: Option<u32>,
+) -> Result<(), String> {
+    let name_u16: Vec<u16> = device_name.encode_utf16().chain(Some(0)).collect();
+    unsafe {
+        let mut dev_mode = std::mem::zeroed::<DEVMODEW>();
+        dev_mode.dmSize = std::mem::size_of::<DEVMODEW>() as u16;
+        if !EnumDisplaySettingsW(
+            PCWSTR(name_u16.as_ptr()),
+            ENUM_CURRENT_SETTINGS,
+            &mut dev_mode,
+        )
+        .as_bool()
+        {
+            return Err(format!("{device_name} is not active"));
+        }
+        dev_mode.dmPelsWidth = width;
+        dev_mode.dmPelsHeight = height;
+        dev_mode.dmFields = DM_PELSWIDTH | DM_PELSHEIGHT;
+        if let Some(hz) = hz {
+            dev_mode.dmDisplayFrequency = hz;
+            dev_mode.dmFields |= DM_DISPLAYFREQUENCY;
+        }
+
+        let describe = || match hz {
+            Some(hz) => format!("{width}×{height}@{hz}Hz"),
+            None => format!("{width}×{height}"),
+        };
+        let test = ChangeDisplaySettingsExW(
+            PCWSTR(name_u16.as_ptr()),
+            Some(&dev_mode),
+            None,
+            CDS_TEST,
+            None,
+        );
+        if test != DISP_CHANGE_SUCCESSFUL {
+            return Err(format!("{} is not supported by {device_name}", describe()));
+        }
+        let result = ChangeDisplaySettingsExW(
+            PCWSTR(name_u16.as_ptr()),
+            Some(&dev_mode),
+            None,
+            CDS_TYPE(0),
+            None,
+        );
+        if result != DISP_CHANGE_SUCCESSFUL {
+            return Err(format!(
+                "switching {device_name} to {} failed (code {})",
+                describe(),
+                result.0
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Windows' display scaling steps, in the order the DPI-scale device info
+/// below counts them.
+pub const SCALE_STEPS: [u32; 12] = [100, 125, 150, 175, 200, 225, 250, 300, 350, 400, 450, 500];
+
+// Undocumented `DisplayConfig*DeviceInfo` packets behind the Settings app's
+// scale picker. Scales are counted in steps from the monitor's recommended one.
+const DISPLAYCONFIG_DEVICE_INFO_GET_DPI_SCALE: DISPLAYCONFIG_DEVICE_INFO_TYPE =
+    DISPLAYCONFIG_DEVICE_INFO_TYPE(-3);
+const DISPLAYCONFIG_DEVICE_INFO_SET_SOURCE_DPI_SCALE: DISPLAYCONFIG_DEVICE_INFO_TYPE =
+    DISPLAYCONFIG_DEVICE_INFO_TYPE(-4);
+
+#[repr(C)]
+struct DpiScaleGet {
+    header: DISPLAYCONFIG_DEVICE_INFO_HEADER,
+    min_rel: i32,
+    cur_rel: i32,
+    max_rel: i32,
+}
+
+#[repr(C)]
+struct DpiScaleSet {
+    header: DISPLAYCONFIG_DEVICE_INFO_HEADER,
+    scale_rel: i32,
+}
+
+/// The active display path whose source is named `device_name`.
+fn active_path(device_name: &str) -> Option<DISPLAYCONFIG_PATH_INFO> {
+    unsafe {
+        let mut path_count = 0;
+        let mut mode_count = 0;
+        if GetDisplayConfigBufferSizes(QDC_ONLY_ACTIVE_PATHS, &mut path_count, &mut mode_count)
+            != windows::Win32::Foundation::WIN32_ERROR(0)
+        {
+            return None;
+        }
+        let mut paths = vec![DISPLAYCONFIG_PATH_INFO::default(); path_count as usize];
+        let mut modes = vec![DISPLAYCONFIG_MODE_INFO::default(); mode_count as usize];
+        if QueryDisplayConfig(
+            QDC_ONLY_ACTIVE_PATHS,
+            &mut path_count,
+            paths.as_mut_ptr(),
+            &mut mode_count,
+            modes.as_mut_ptr(),
+            None,
+        ) != windows::Win32::Foundation::WIN32_ERROR(0)
+        {
+            return None;
+        }
+        paths.into_iter().take(path_count as usize).find(|path| {
+            let mut source_name = DISPLAYCONFIG_SOURCE_DEVICE_NAME::default();
+            source_name.header.r#type = DISPLAYCONFIG_DEVICE_INFO_GET_SOURCE_NAME;
+            source_name.header.size =
+                std::mem::size_of::<DISPLAYCONFIG_SOURCE_DEVICE_NAME>() as u32;
+            source_name.header.adapterId = path.sourceInfo.adapterId;
+            source_name.header.id = path.sourceInfo.id;
+            DisplayConfigGetDeviceInfo(&mut source_name.header) == 0
+                && String::from_utf16_lossy(&source_name.viewGdiDeviceName)
+                    .trim_matches(char::from(0))
+                    == device_name
+        })
+    }
+}
+
+/// Packet header addressing the active source named `device_name`.
+fn source_info_header(
+    device_name: &str,
+    kind: DISPLAYCONFIG_DEVICE_INFO_TYPE,
+    size: usize,
+) -> Option<DISPLAYCONFIG_DEVICE_INFO_HEADER> {
+    let path = active_path(device_name)?;
+    Some(DISPLAYCONFIG_DEVICE_INFO_HEADER {
+        r#type: kind,
+        size: size as u32,
+        adapterId: path.sourceInfo.adapterId,
+        id: path.sourceInfo.id,
+    })
+}
+
+/// Packet header addressing the monitor (target) shown by the source named
+/// `device_name`.
+fn target_info_header(
+    device_name: &str,
+    kind: DISPLAYCONFIG_DEVICE_INFO_TYPE,
+    size: usize,
+) -> Option<DISPLAYCONFIG_DEVICE_INFO_HEADER> {
+    let path = active_path(device_name)?;
+    Some(DISPLAYCONFIG_DEVICE_INFO_HEADER {
+        r#type: kind,
+        size: size as u32,
+        adapterId: path.targetInfo.adapterId,
+        id: path.targetInfo.id,
+    })
+}
+
+/// The monitor's scaling range and current scale, in steps relative to its
+/// recommended scale, plus the recommended scale's index in [`SCALE_STEPS`].
+fn dpi_scale(device_name: &str) -> Option<(DpiScaleGet, i32)> {
+    let header = source_info_header(
+        device_name,
+        DISPLAYCONFIG_DEVICE_INFO_GET_DPI_SCALE,
+        std::mem::size_of::<DpiScaleGet>(),
+    )?;
+    let mut packet = DpiScaleGet {
+        header,
+        min_rel: 0,
+        cur_rel: 0,
+        max_rel: 0,
+    };
+    if unsafe { DisplayConfigGetDeviceInfo(&mut packet.header) } != 0 {
+        return None;
+    }
+    // The smallest step Windows offers is 100%, so the recommended scale
+    // sits `-min_rel` steps above it.
+    let recommended = -packet.min_rel;
+    Some((packet, recommended))
+}
+
+/// The monitor's current display scaling in percent.
+pub fn current_scale_percent(device_name: &str) -> Option<u32> {
+    let (packet, recommended) = dpi_scale(device_name)?;
+    SCALE_STEPS
+        .get(usize::try_from(recommended + packet.cur_rel).ok()?)
+        .copied()
+}
+
+/// Set the monitor's display scaling to `percent`. This goes through an
+/// undocumented API, so success only means Windows accepted the request;
+/// check [`current_scale_percent`] to see whether it took effect.
+pub fn set_scale_percent(device_name: &str, percent: u32) -> Result<(), String> {
+    let (limits, recommended) =
+        dpi_scale(device_name).ok_or("could not read the monitor's scaling")?;
+    let step = SCALE_STEPS
+        .iter()
+        .position(|&s| s == percent)
+        .ok_or(format!("{percent}% is not a Windows scaling step"))?;
+    let scale_rel = step as i32 - recommended;
+    if scale_rel < limits.min_rel || scale_rel > limits.max_rel {
+        let max = SCALE_STEPS
+            .get((recommended + limits.max_rel).max(0) as usize)
+            .copied()
+            .unwrap_or(100);
+        return Err(format!(
+            "{percent}% is above this monitor's maximum of {max}%"
+        ));
+    }
+    let header = source_info_header(
+        device_name,
+        DISPLAYCONFIG_DEVICE_INFO_SET_SOURCE_DPI_SCALE,
+        std::mem::size_of::<DpiScaleSet>(),
+    )
+    .ok_or("monitor not found")?;
+    let packet = DpiScaleSet { header, scale_rel };
+    match unsafe { DisplayConfigSetDeviceInfo(&packet.header) } {
+        0 => Ok(()),
+        code => Err(format!("Windows rejected the change (error {code})")),
+    }
+}
+
+/// Whether Windows HDR (advanced color) is on for the monitor, or `None` if
+/// the monitor doesn't support it or couldn't be queried.
+pub fn hdr_enabled(device_name: &str) -> Option<bool> {
+    let mut info = DISPLAYCONFIG_GET_ADVANCED_COLOR_INFO {
+        header: target_info_header(
+            device_name,
+            DISPLAYCONFIG_DEVICE_INFO_GET_ADVANCED_COLOR_INFO,
+            std::mem::size_of::<DISPLAYCONFIG_GET_ADVANCED_COLOR_INFO>(),
+        )?,
+        ..Default::default()
+    };
+    unsafe {
+        if DisplayConfigGetDeviceInfo(&mut info.header) != 0 {
+            return None;
+        }
+        // Bit 0: advancedColorSupported, bit 1: advancedColorEnabled.
+        let bits = info.Anonymous.value;
+        (bits & 1 != 0).then_some(bits & 2 != 0)
+    }
+}
+
+/// Turn Windows HDR on or off for the monitor.
+pub fn set_hdr(device_name: &str, enabled: bool) -> Result<(), String> {
+    let mut state = DISPLAYCONFIG_SET_ADVANCED_COLOR_STATE {
+        header: target_info_header(
+            device_name,
+            DISPLAYCONFIG_DEVICE_INFO_SET_ADVANCED_COLOR_STATE,
+            std::mem::size_of::<DISPLAYCONFIG_SET_ADVANCED_COLOR_STATE>(),
+        )
+        .ok_or("monitor not found")?,
+        ..Default::default()
+    };
+    // Bit 0: enableAdvancedColor.
+    state.Anonymous.value = enabled as u32;
+    match unsafe { DisplayConfigSetDeviceInfo(&state.header) } {
+        0 => Ok(()),
+        code => Err(format!("Windows rejected the change (error {code})")),
+    }
+}
+
+/// Restore monitor positions (and display modes, where saved) from a
+/// snapshot. Monitors that aren't connected are skipped; the error names the
+/// ones Windows refused.
+pub fn restore_monitor_layout(snapshot: &[SavedMonitorPos]) -> Result<(), String> {
+    let mut failed = Vec::new();
     unsafe {
         for saved in snapshot {
             let name_u16: Vec<u16> = saved.device_name.encode_utf16().chain(Some(0)).collect();
@@ -273,20 +750,40 @@ pub fn restore_monitor_layout(snapshot: &[SavedMonitorPos]) {
             dev_mode.dmFields = DM_POSITION;
             dev_mode.Anonymous1.Anonymous2.dmPosition.x = saved.rect.left;
             dev_mode.Anonymous1.Anonymous2.dmPosition.y = saved.rect.top;
+            if let Some(mode) = saved.mode {
+                dev_mode.dmPelsWidth = mode.width;
+                dev_mode.dmPelsHeight = mode.height;
+                dev_mode.dmFields |= DM_PELSWIDTH | DM_PELSHEIGHT;
+                if mode.hz > 0 {
+                    dev_mode.dmDisplayFrequency = mode.hz;
+                    dev_mode.dmFields |= DM_DISPLAYFREQUENCY;
+                }
+            }
             // Mark as primary if it was primary (i.e., its top-left was (0,0))
             let mut flags = CDS_UPDATEREGISTRY | CDS_NORESET | CDS_GLOBAL;
             if saved.rect.left == 0 && saved.rect.top == 0 {
                 flags |= CDS_SET_PRIMARY;
             }
-            ChangeDisplaySettingsExW(
+            let result = ChangeDisplaySettingsExW(
                 PCWSTR(name_u16.as_ptr()),
                 Some(&dev_mode),
                 None,
                 flags,
                 None,
             );
+            if result != DISP_CHANGE_SUCCESSFUL {
+                failed.push(format!("{} (code {})", saved.device_name, result.0));
+            }
         }
-        let _ = ChangeDisplaySettingsExW(PCWSTR(ptr::null()), None, None, CDS_TYPE(0), None);
+        let result = ChangeDisplaySettingsExW(PCWSTR(ptr::null()), None, None, CDS_TYPE(0), None);
+        if result != DISP_CHANGE_SUCCESSFUL {
+            return Err(format!("Windows rejected the layout (code {})", result.0));
+        }
+    }
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("Windows rejected {}", failed.join(", ")))
     }
 }
 
@@ -438,6 +935,86 @@ pub fn set_monitor_state(target_id: u32, state: &str) {
     }
 }
 
+/// The active display paths and modes, as captured by
+/// [`disable_other_monitors`] so the topology can be put back exactly.
+pub struct DisplayTopology {
+    paths: Vec<DISPLAYCONFIG_PATH_INFO>,
+    modes: Vec<DISPLAYCONFIG_MODE_INFO>,
+}
+
+/// Turn off every active monitor except the one named `keep_device` (and any
+/// duplicating it) for this session only. Returns the topology from before the
+/// change for [`restore_topology`].
+pub fn disable_other_monitors(keep_device: &str) -> Result<DisplayTopology, String> {
+    let keep = active_path(keep_device).ok_or("monitor not found")?;
+    unsafe {
+        let mut path_count = 0;
+        let mut mode_count = 0;
+        if GetDisplayConfigBufferSizes(QDC_ONLY_ACTIVE_PATHS, &mut path_count, &mut mode_count)
+            != windows::Win32::Foundation::WIN32_ERROR(0)
+        {
+            return Err("could not read the display configuration".to_string());
+        }
+        let mut paths = vec![DISPLAYCONFIG_PATH_INFO::default(); path_count as usize];
+        let mut modes = vec![DISPLAYCONFIG_MODE_INFO::default(); mode_count as usize];
+        if QueryDisplayConfig(
+            QDC_ONLY_ACTIVE_PATHS,
+            &mut path_count,
+            paths.as_mut_ptr(),
+            &mut mode_count,
+            modes.as_mut_ptr(),
+            None,
+        ) != windows::Win32::Foundation::WIN32_ERROR(0)
+        {
+            return Err("could not read the display configuration".to_string());
+        }
+        paths.truncate(path_count as usize);
+        modes.truncate(mode_count as usize);
+
+        let same_source = |p: &DISPLAYCONFIG_PATH_INFO| {
+            p.sourceInfo.id == keep.sourceInfo.id
+                && p.sourceInfo.adapterId.LowPart == keep.sourceInfo.adapterId.LowPart
+                && p.sourceInfo.adapterId.HighPart == keep.sourceInfo.adapterId.HighPart
+        };
+        let mut kept: Vec<DISPLAYCONFIG_PATH_INFO> =
+            paths.iter().filter(|p| same_source(p)).cloned().collect();
+        if kept.len() == paths.len() {
+            return Err("no other monitors are on".to_string());
+        }
+        for p in &mut kept {
+            p.sourceInfo.Anonymous.modeInfoIdx = 0xFFFFFFFF;
+            p.targetInfo.Anonymous.modeInfoIdx = 0xFFFFFFFF;
+        }
+        // Not saved to the database, so a reboot or replug also brings the
+        // other monitors back if DisplayWarp never gets to restore them.
+        let code = SetDisplayConfig(
+            Some(&kept),
+            None,
+            SDC_APPLY | SDC_USE_SUPPLIED_DISPLAY_CONFIG | SDC_ALLOW_CHANGES,
+        );
+        if code != 0 {
+            return Err(format!("Windows rejected the change (error {code})"));
+        }
+        Ok(DisplayTopology { paths, modes })
+    }
+}
+
+/// Put back a topology captured by [`disable_other_monitors`].
+pub fn restore_topology(topology: &DisplayTopology) -> Result<(), String> {
+    let code = unsafe {
+        SetDisplayConfig(
+            Some(&topology.paths),
+            Some(&topology.modes),
+            SDC_APPLY | SDC_USE_SUPPLIED_DISPLAY_CONFIG | SDC_ALLOW_CHANGES,
+        )
+    };
+    match code {
+        0 => Ok(()),
+        code => Err(format!("Windows rejected the change (error {code})")),
+    }
+}
+
+#[allow(dead_code)]
 pub fn set_primary_monitor(target_id: u32) {
     unsafe {
         let mut path_count = 0;
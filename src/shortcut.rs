@@ -0,0 +1,47 @@
+use std::path::{Path, PathBuf};
+
+use windows::Win32::System::Com::{
+    CLSCTX_INPROC_SERVER, CoCreateInstance, IPersistFile, STGM_READ,
+};
+use windows::Win32::UI::Shell::{IShellLinkW, ShellLink};
+use windows::core::{HSTRING, Interface, PCWSTR};
+
+/// Returns true if `path` looks like a Windows shell shortcut.
+pub fn is_shortcut(path: &Path) -> bool {
+    path.extension()
+        .map(|e| e.eq_ignore_ascii_case("lnk"))
+        .unwrap_or(false)
+}
+
+/// Resolve a `.lnk` shortcut to its target executable and argument string.
+/// Requires COM to be initialized on the calling thread.
+pub fn resolve_shortcut(path: &Path) -> Option<(PathBuf, String)> {
+    unsafe {
+        let link: IShellLinkW = CoCreateInstance(&ShellLink, None, CLSCTX_INPROC_SERVER).ok()?;
+        let persist: IPersistFile = link.cast().ok()?;
+        let wide_path = HSTRING::from(path.as_os_str());
+        persist.Load(PCWSTR(wide_path.as_ptr()), STGM_READ).ok()?;
+
+        let mut target_buf = [0u16; 1024];
+        link.GetPath(&mut target_buf, std::ptr::null_mut(), 0)
+            .ok()?;
+        let target = wide_to_string(&target_buf);
+        if target.is_empty() {
+            return None;
+        }
+
+        let mut args_buf = [0u16; 1024];
+        let args = if link.GetArguments(&mut args_buf).is_ok() {
+            wide_to_string(&args_buf)
+        } else {
+            String::new()
+        };
+
+        Some((PathBuf::from(target), args))
+    }
+}
+
+fn wide_to_string(buf: &[u16]) -> String {
+    let len = buf.iter().position(|&c| c == 0).unwrap_or(buf.len());
+    String::from_utf16_lossy(&buf[..len])
+}
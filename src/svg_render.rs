@@ -1,8 +1,18 @@
+use std::sync::OnceLock;
+
 /// Decode a PNG file (bytes) into RGBA pixels, width, and height.
-pub fn png_to_rgba(png_data: &[u8]) -> (Vec<u8>, u32, u32) {
+/// Returns `None` for a corrupt asset instead of panicking at startup.
+pub fn png_to_rgba(png_data: &[u8]) -> Option<(Vec<u8>, u32, u32)> {
     let img = image::load_from_memory_with_format(png_data, image::ImageFormat::Png)
-        .expect("failed to decode PNG")
+        .ok()?
         .into_rgba8();
     let (w, h) = img.dimensions();
-    (img.into_raw(), w, h)
+    Some((img.into_raw(), w, h))
+}
+
+/// The 64px app icon, decoded once and shared by the titlebar and tray.
+pub fn app_icon_rgba() -> Option<&'static (Vec<u8>, u32, u32)> {
+    static ICON: OnceLock<Option<(Vec<u8>, u32, u32)>> = OnceLock::new();
+    ICON.get_or_init(|| png_to_rgba(include_bytes!("../assets/DisplayWarpIcon64.png")))
+        .as_ref()
 }
@@ -7,15 +7,13 @@ use tray_icon::{Icon, TrayIcon, TrayIconBuilder};
 
 use crate::models::{AppProfile, SavedData, SavedDisplayLayout};
 
-fn tray_icon() -> Icon {
-    let (rgba, w, h) =
-        crate::svg_render::png_to_rgba(include_bytes!("../assets/DisplayWarpIcon64.png"));
-    Icon::from_rgba(rgba, w, h).expect("failed to create tray icon")
+fn tray_icon() -> Option<Icon> {
+    let (rgba, w, h) = crate::svg_render::app_icon_rgba()?;
+    Icon::from_rgba(rgba.clone(), *w, *h).ok()
 }
 
-/// Detects whether Windows is currently in dark mode.
-/// Returns `true` for dark mode, `false` for light mode.
-fn is_dark_mode() -> bool {
+/// True if Windows is set to dark mode for apps (`AppsUseLightTheme` = 0).
+pub fn is_dark_mode() -> bool {
     use windows::Win32::System::Registry::{
         HKEY_CURRENT_USER, KEY_READ, RegCloseKey, RegOpenKeyExW, RegQueryValueExW,
     };
@@ -159,7 +157,9 @@ fn apply_window_dark_mode(dark: bool) {
 
 #[derive(Default, Clone)]
 pub struct TrayState {
-    pub warp_ids: Vec<MenuId>,
+    /// Menu id and profile name. Clicks resolve by name so a menu built
+    /// before a reorder or delete never launches the wrong profile.
+    pub warp_ids: Vec<(MenuId, String)>,
     pub display_ids: Vec<MenuId>,
 }
 
@@ -200,7 +200,9 @@ impl TrayItems {
         } else {
             for p in profiles {
                 let item = MenuItem::new(format!("{}{}", warp_icon, p.name), true, None);
-                next_state.warp_ids.push(item.id().clone());
+                next_state
+                    .warp_ids
+                    .push((item.id().clone(), p.name.clone()));
                 let _ = warp_sub.append(&item);
             }
         }
@@ -229,13 +231,14 @@ impl TrayItems {
     }
 }
 
-/// Create the system-tray icon and context menu.
+/// Create the system-tray icon and context menu. Returns `None` if the shell
+/// refused the icon (e.g. Explorer is not running).
 pub fn create_tray(
     watcher_running: Arc<AtomicBool>,
     data: Arc<Mutex<SavedData>>,
     status_message: Arc<Mutex<String>>,
     status_log: Arc<Mutex<Vec<String>>>,
-) -> TrayItems {
+) -> Option<TrayItems> {
     let dark = is_dark_mode();
 
     if dark {
@@ -272,7 +275,7 @@ pub fn create_tray(
     } else {
         for p in &profiles_snapshot {
             let item = MenuItem::new(format!("{}{}", warp_icon, p.name), true, None);
-            state.warp_ids.push(item.id().clone());
+            state.warp_ids.push((item.id().clone(), p.name.clone()));
             let _ = warp_sub.append(&item);
         }
     }
@@ -294,13 +297,13 @@ pub fn create_tray(
     let _ = menu.append(&PredefinedMenuItem::separator());
     let _ = menu.append(&quit_item);
 
-    let icon = tray_icon();
+    let icon = tray_icon()?;
     let tray = TrayIconBuilder::new()
         .with_menu(Box::new(menu))
         .with_tooltip("DisplayWarp")
         .with_icon(icon)
         .build()
-        .expect("failed to build tray icon");
+        .ok()?;
 
     let state_arc = Arc::new(Mutex::new(state));
     let state_thread = Arc::clone(&state_arc);
@@ -312,6 +315,7 @@ pub fn create_tray(
                 let id = event.id().clone();
                 if id == quit_id {
                     watcher_running.store(false, Ordering::Relaxed);
+                    crate::file_log::flush();
                     std::process::exit(0);
                 } else if id == show_id {
                     show_window_native();
@@ -319,25 +323,24 @@ pub fn create_tray(
                     let st = state_thread.lock().clone();
 
                     // Check Warp Profiles
-                    let mut found = false;
-                    for (idx, pid) in st.warp_ids.iter().enumerate() {
-                        if id == *pid {
-                            found = true;
-                            let profile = {
-                                let d = data.lock();
-                                d.profiles.get(idx).cloned()
-                            };
-                            if let Some(p) = profile {
-                                crate::app::WindowManagerApp::launch_profile(
-                                    &p,
-                                    Arc::clone(&status_message),
-                                    Arc::clone(&status_log),
-                                );
-                            }
-                            break;
+                    if let Some((_, name)) = st.warp_ids.iter().find(|(pid, _)| id == *pid) {
+                        let profile = {
+                            let d = data.lock();
+                            d.profiles.iter().find(|p| &p.name == name).cloned()
+                        };
+                        match profile {
+                            Some(p) => crate::app::WindowManagerApp::launch_profile(
+                                &p,
+                                Arc::clone(&data),
+                                Arc::clone(&status_message),
+                                Arc::clone(&status_log),
+                            ),
+                            None => crate::app::WindowManagerApp::push_status(
+                                &status_message,
+                                &status_log,
+                                format!("❌ Profile '{name}' no longer exists."),
+                            ),
                         }
-                    }
-                    if found {
                         continue;
                     }
 
@@ -363,13 +366,13 @@ pub fn create_tray(
         }
     });
 
-    TrayItems {
+    Some(TrayItems {
         tray,
         state: state_arc,
-    }
+    })
 }
 
-fn show_window_native() {
+pub fn show_window_native() {
     unsafe {
         use windows::Win32::UI::WindowsAndMessaging::{
             BringWindowToTop, FindWindowW, GWL_EXSTYLE, GetWindowLongW, SW_HIDE, SW_RESTORE,
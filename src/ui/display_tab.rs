@@ -1,8 +1,10 @@
+use std::sync::Arc;
+
 use eframe::egui;
 use egui_phosphor::regular;
 
 use crate::app::WindowManagerApp;
-use crate::ui::helpers::truncate_text;
+use crate::ui::helpers::{muted_text, truncate_text};
 use windows::Win32::Foundation::RECT;
 
 pub fn draw_display_tab(app: &mut WindowManagerApp, ui: &mut egui::Ui, available_h: f32) {
@@ -56,11 +58,7 @@ pub fn draw_display_tab(app: &mut WindowManagerApp, ui: &mut egui::Ui, available
                                     "Drag and drop monitors to match your physical layout.",
                                 )
                                 .small()
-                                .color(if app.dark_mode {
-                                    egui::Color32::from_gray(140)
-                                } else {
-                                    egui::Color32::from_gray(100)
-                                }),
+                                .color(muted_text(app.dark_mode)),
                             );
                         });
 
@@ -72,6 +70,25 @@ pub fn draw_display_tab(app: &mut WindowManagerApp, ui: &mut egui::Ui, available
                             {
                                 app.refresh_monitors();
                             }
+                            if app.layout_dirty {
+                                if ui
+                                    .button(format!("{} Revert", regular::ARROW_COUNTER_CLOCKWISE))
+                                    .on_hover_text("Discard the dragged arrangement")
+                                    .clicked()
+                                {
+                                    app.refresh_monitors();
+                                }
+                                if ui
+                                    .button(format!("{} Apply Layout", regular::CHECK))
+                                    .on_hover_text(format!(
+                                        "Move the real displays to match. Reverts after {}s unless you keep it",
+                                        crate::app::PRIMARY_REVERT_SECS
+                                    ))
+                                    .clicked()
+                                {
+                                    app.apply_arranged_layout();
+                                }
+                            }
                         });
                     });
                     ui.add_space(8.0);
@@ -254,8 +271,28 @@ pub fn draw_display_tab(app: &mut WindowManagerApp, ui: &mut egui::Ui, available
                                 let target_top =
                                     (orig_rect.top + delta_virtual_y).clamp(-16000, 16000);
 
-                                let snapped_left = (target_left as f32 / 40.0).round() as i32 * 40;
-                                let snapped_top = (target_top as f32 / 40.0).round() as i32 * 40;
+                                // Edges within ~12 screen px of a neighbour snap to it;
+                                // otherwise fall back to the grid.
+                                let neighbours: Vec<RECT> = grouped_monitors
+                                    .iter()
+                                    .filter(|g| !g.1.contains(&idx))
+                                    .map(|g| g.0)
+                                    .collect();
+                                let threshold = (12.0 / scale) as i32;
+                                let snapped_left = snap_edge(
+                                    target_left,
+                                    width,
+                                    neighbours.iter().map(|r| (r.left, r.right)),
+                                    threshold,
+                                )
+                                .unwrap_or((target_left as f32 / 40.0).round() as i32 * 40);
+                                let snapped_top = snap_edge(
+                                    target_top,
+                                    height,
+                                    neighbours.iter().map(|r| (r.top, r.bottom)),
+                                    threshold,
+                                )
+                                .unwrap_or((target_top as f32 / 40.0).round() as i32 * 40);
 
                                 let new_rect = crate::models::SerializableRect {
                                     left: snapped_left,
@@ -287,6 +324,7 @@ pub fn draw_display_tab(app: &mut WindowManagerApp, ui: &mut egui::Ui, available
                                 }
 
                                 if !collision {
+                                    app.layout_dirty = true;
                                     // Move all monitors in the group
                                     if let Some(grp) = grouped_monitors
                                         .iter()
@@ -408,7 +446,7 @@ pub fn draw_display_tab(app: &mut WindowManagerApp, ui: &mut egui::Ui, available
                                     .join(" | ")
                             )
                         } else {
-                            format!("Monitor {}", indices[0] + 1)
+                            app.monitor_label(indices[0])
                         };
 
                         painter.text(
@@ -483,6 +521,7 @@ pub fn draw_display_tab(app: &mut WindowManagerApp, ui: &mut egui::Ui, available
                                 [90.0, 24.0],
                                 egui::Button::new(format!("{} Save", regular::FLOPPY_DISK)),
                             )
+                            .on_hover_text("Save every monitor's position, primary flag, resolution and refresh rate")
                             .clicked()
                             && !app.new_display_profile_name.is_empty()
                         {
@@ -497,6 +536,7 @@ pub fn draw_display_tab(app: &mut WindowManagerApp, ui: &mut egui::Ui, available
                                         right: m.rect.right,
                                         bottom: m.rect.bottom,
                                     },
+                                    mode: crate::monitor::current_display_mode(&m.device_name),
                                 })
                                 .collect();
 
@@ -550,11 +590,7 @@ pub fn draw_display_tab(app: &mut WindowManagerApp, ui: &mut egui::Ui, available
                     ui.label(
                         egui::RichText::new("Click a monitor in the canvas above to configure it.")
                             .small()
-                            .color(if app.dark_mode {
-                                egui::Color32::from_gray(140)
-                            } else {
-                                egui::Color32::from_gray(100)
-                            }),
+                            .color(muted_text(app.dark_mode)),
                     );
                     ui.add_space(8.0);
 
@@ -706,16 +742,42 @@ pub fn draw_display_tab(app: &mut WindowManagerApp, ui: &mut egui::Ui, available
                                     let mut check_primary = is_primary;
                                     if ui
                                         .checkbox(&mut check_primary, "Make this my main display")
+                                        .on_hover_text(format!(
+                                            "Reverts after {}s unless you keep it",
+                                            crate::app::PRIMARY_REVERT_SECS
+                                        ))
                                         .changed()
                                         && check_primary
                                     {
-                                        if let Some(target_id) = selected_mon.target_id {
-                                            crate::monitor::set_primary_monitor(target_id);
-                                            std::thread::sleep(std::time::Duration::from_millis(
-                                                500,
-                                            ));
-                                            app.refresh_monitors();
-                                        }
+                                        app.switch_primary(&selected_mon.device_name);
+                                    }
+                                    if app.primary_switch_snapshot.is_some()
+                                        && app.primary_revert_deadline.is_none()
+                                        && ui
+                                            .button(format!(
+                                                "{} Undo last primary switch",
+                                                regular::ARROW_COUNTER_CLOCKWISE
+                                            ))
+                                            .clicked()
+                                    {
+                                        app.undo_primary_switch();
+                                    }
+
+                                    ui.add_space(4.0);
+                                    if ui
+                                        .add_enabled(
+                                            !is_primary,
+                                            egui::Button::new(format!(
+                                                "{} Move all windows off this monitor",
+                                                regular::LIFEBUOY
+                                            )),
+                                        )
+                                        .on_hover_text(
+                                            "Sends every window on it to the primary monitor",
+                                        )
+                                        .clicked()
+                                    {
+                                        app.rescue_windows(Some(rect));
                                     }
                                 });
                         }
@@ -726,6 +788,106 @@ pub fn draw_display_tab(app: &mut WindowManagerApp, ui: &mut egui::Ui, available
                             ui.add_space(10.0);
                         });
                     }
+
+                    ui.add_space(4.0);
+                    if ui
+                        .button(format!("{} Rescue off-screen windows", regular::LIFEBUOY))
+                        .on_hover_text(
+                            "Bring back windows stranded on a disconnected or dead monitor",
+                        )
+                        .clicked()
+                    {
+                        app.rescue_windows(None);
+                    }
+
+                    ui.add_space(4.0);
+                    egui::CollapsingHeader::new(format!("{} Edit monitor labels", regular::TAG))
+                        .id_salt("monitor_labels")
+                        .show(ui, |ui| {
+                            let mut changed = false;
+                            {
+                                let mut data = app.data.lock();
+                                for (i, m) in app.monitors.iter().enumerate() {
+                                    let mut label =
+                                        data.monitor_label(m).cloned().unwrap_or_default();
+                                    let resp = ui.add(
+                                        egui::TextEdit::singleline(&mut label)
+                                            .hint_text(format!("Monitor {}", i + 1))
+                                            .desired_width(ui.available_width()),
+                                    );
+                                    if resp.changed() {
+                                        // Blank labels fall back to the numbered name.
+                                        data.set_monitor_label(m, &label);
+                                    }
+                                    changed |= resp.lost_focus();
+                                }
+                            }
+                            if changed {
+                                app.save_data();
+                            }
+                        });
+
+                    egui::CollapsingHeader::new(format!(
+                        "{} Audio per monitor",
+                        regular::SPEAKER_HIGH
+                    ))
+                    .id_salt("monitor_audio")
+                    .show(ui, |ui| {
+                        ui.label(
+                            egui::RichText::new(
+                                "Switch the audio output when the focused window moves onto a monitor.",
+                            )
+                            .small()
+                            .weak(),
+                        );
+                        let names: Vec<String> =
+                            (0..app.monitors.len()).map(|i| app.monitor_label(i)).collect();
+                        let mut changed = false;
+                        {
+                            let mut data = app.data.lock();
+                            for (m, name) in app.monitors.iter().zip(&names) {
+                                let key = m.label_key().to_string();
+                                let current = data.monitor_audio.get(&key).cloned();
+                                let selected = current
+                                    .as_ref()
+                                    .map(|id| {
+                                        app.audio_devices
+                                            .iter()
+                                            .find(|d| &d.id == id)
+                                            .map(|d| d.name.clone())
+                                            .unwrap_or_else(|| "Disconnected device".into())
+                                    })
+                                    .unwrap_or_else(|| "Don't change".into());
+                                let mut choice = current.clone();
+                                ui.horizontal(|ui| {
+                                    ui.label(name);
+                                    egui::ComboBox::from_id_salt(("monitor_audio", &key))
+                                        .selected_text(selected)
+                                        .width(ui.available_width())
+                                        .show_ui(ui, |ui| {
+                                            ui.selectable_value(&mut choice, None, "Don't change");
+                                            for d in &app.audio_devices {
+                                                ui.selectable_value(
+                                                    &mut choice,
+                                                    Some(d.id.clone()),
+                                                    &d.name,
+                                                );
+                                            }
+                                        });
+                                });
+                                if choice != current {
+                                    match choice {
+                                        Some(id) => data.monitor_audio.insert(key, id),
+                                        None => data.monitor_audio.remove(&key),
+                                    };
+                                    changed = true;
+                                }
+                            }
+                        }
+                        if changed {
+                            app.save_data();
+                        }
+                    });
                 }); // End Monitor Settings
 
             ui.add_space(4.0);
@@ -759,11 +921,7 @@ pub fn draw_display_tab(app: &mut WindowManagerApp, ui: &mut egui::Ui, available
                     ui.label(
                         egui::RichText::new("Quickly restore a saved multi-monitor layout.")
                             .small()
-                            .color(if app.dark_mode {
-                                egui::Color32::from_gray(140)
-                            } else {
-                                egui::Color32::from_gray(100)
-                            }),
+                            .color(muted_text(app.dark_mode)),
                     );
                     ui.add_space(8.0);
 
@@ -821,6 +979,20 @@ pub fn draw_display_tab(app: &mut WindowManagerApp, ui: &mut egui::Ui, available
                                                 );
                                             });
 
+                                            let modes: Vec<String> = p
+                                                .monitors
+                                                .iter()
+                                                .filter_map(|m| m.mode)
+                                                .map(|m| format!("{}×{}@{}", m.width, m.height, m.hz))
+                                                .collect();
+                                            if !modes.is_empty() {
+                                                ui.label(
+                                                    egui::RichText::new(modes.join(", "))
+                                                        .small()
+                                                        .color(muted_text(app.dark_mode)),
+                                                );
+                                            }
+
                                             ui.add_space(4.0);
 
                                             // Bottom Row: The 3 main action buttons
@@ -829,13 +1001,10 @@ pub fn draw_display_tab(app: &mut WindowManagerApp, ui: &mut egui::Ui, available
                                                     .button(format!("{} Apply", regular::PLAY))
                                                     .clicked()
                                                 {
-                                                    crate::monitor::restore_monitor_layout(
-                                                        &p.monitors,
-                                                    );
-                                                    WindowManagerApp::push_status(
-                                                        &app.status_message,
-                                                        &app.status_log,
-                                                        format!("✅ Applied '{}'", p.name),
+                                                    WindowManagerApp::apply_display_layout(
+                                                        p,
+                                                        Arc::clone(&app.status_message),
+                                                        Arc::clone(&app.status_log),
                                                     );
                                                     app.refresh_monitors();
                                                 }
@@ -871,11 +1040,6 @@ pub fn draw_display_tab(app: &mut WindowManagerApp, ui: &mut egui::Ui, available
                         if i > 0 {
                             app.data.lock().display_profiles.swap(i, i - 1);
                             app.save_data();
-                            let profiles = app.data.lock().profiles.clone();
-                            let display_profiles = app.data.lock().display_profiles.clone();
-                            if let Some(t) = &app.tray {
-                                t.refresh_menu(&profiles, &display_profiles);
-                            }
                         }
                     }
 
@@ -884,11 +1048,6 @@ pub fn draw_display_tab(app: &mut WindowManagerApp, ui: &mut egui::Ui, available
                         if i < len - 1 {
                             app.data.lock().display_profiles.swap(i, i + 1);
                             app.save_data();
-                            let profiles = app.data.lock().profiles.clone();
-                            let display_profiles = app.data.lock().display_profiles.clone();
-                            if let Some(t) = &app.tray {
-                                t.refresh_menu(&profiles, &display_profiles);
-                            }
                         }
                     }
 
@@ -897,12 +1056,6 @@ pub fn draw_display_tab(app: &mut WindowManagerApp, ui: &mut egui::Ui, available
                         app.data.lock().display_profiles.remove(idx);
                         app.save_data();
 
-                        let profiles = app.data.lock().profiles.clone();
-                        let display_profiles = app.data.lock().display_profiles.clone();
-                        if let Some(t) = &app.tray {
-                            t.refresh_menu(&profiles, &display_profiles);
-                        }
-
                         crate::app::WindowManagerApp::push_status(
                             &app.status_message,
                             &app.status_log,
@@ -913,3 +1066,17 @@ pub fn draw_display_tab(app: &mut WindowManagerApp, ui: &mut egui::Ui, available
         });
     });
 }
+
+/// Snap a dragged span (`start`, `len`) to a neighbour's edge within
+/// `threshold`: touching either side, or aligned with its start or end.
+fn snap_edge(
+    start: i32,
+    len: i32,
+    neighbours: impl Iterator<Item = (i32, i32)>,
+    threshold: i32,
+) -> Option<i32> {
+    neighbours
+        .flat_map(|(lo, hi)| [hi, lo - len, lo, hi - len])
+        .filter(|cand| (cand - start).abs() <= threshold)
+        .min_by_key(|cand| (cand - start).abs())
+}
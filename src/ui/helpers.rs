@@ -1,3 +1,19 @@
+use eframe::egui;
+use std::sync::atomic::Ordering;
+
+use crate::ui::HIGH_CONTRAST;
+
+/// Colour for secondary text (hints, captions). In high-contrast mode it is
+/// the full foreground colour instead of a gray.
+pub fn muted_text(dark_mode: bool) -> egui::Color32 {
+    match (HIGH_CONTRAST.load(Ordering::Relaxed), dark_mode) {
+        (true, true) => egui::Color32::WHITE,
+        (true, false) => egui::Color32::BLACK,
+        (false, true) => egui::Color32::from_gray(140),
+        (false, false) => egui::Color32::from_gray(100),
+    }
+}
+
 pub fn truncate_text(text: &str, max_chars: usize) -> String {
     if text.chars().count() > max_chars {
         text.chars().take(max_chars - 1).collect::<String>() + "…"
@@ -5,3 +21,16 @@ pub fn truncate_text(text: &str, max_chars: usize) -> String {
         text.to_string()
     }
 }
+
+/// Show the executable picker. Shortcuts (`.lnk`) are accepted and resolved to
+/// their target; the returned string holds the shortcut's arguments, if any.
+pub fn pick_executable() -> Option<(std::path::PathBuf, String)> {
+    let path = rfd::FileDialog::new()
+        .add_filter("Executable or shortcut", &["exe", "lnk"])
+        .pick_file()?;
+    if crate::shortcut::is_shortcut(&path) {
+        crate::shortcut::resolve_shortcut(&path)
+    } else {
+        Some((path, String::new()))
+    }
+}
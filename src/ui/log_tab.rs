@@ -2,11 +2,12 @@ use eframe::egui;
 use egui_phosphor::regular;
 
 use crate::app::WindowManagerApp;
+use crate::ui::helpers::muted_text;
 
 // ─── Status / Log Bar ────────────────────────────────────────────────────────
 
 pub fn draw_status_bar(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
-    // ── Header row with Clear button ─────────────────────────────────────────
+    // ── Header row with Copy and Clear buttons ───────────────────────────────
     ui.horizontal(|ui| {
         ui.label(
             egui::RichText::new(format!("{} Activity Log", regular::LIST_BULLETS))
@@ -24,6 +25,16 @@ pub fn draw_status_bar(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
                 l.clear();
                 l.push("🗑 Log cleared.".to_string());
             }
+            if ui
+                .add(egui::Button::new(
+                    egui::RichText::new(format!("{} Copy", regular::COPY)).small(),
+                ))
+                .on_hover_text("Copy the whole log, e.g. for a bug report")
+                .clicked()
+            {
+                let text = app.status_log.lock().join("\n");
+                ui.ctx().copy_text(text);
+            }
         });
     });
 
@@ -63,26 +74,50 @@ pub fn draw_status_bar(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
                         } else {
                             line.as_str()
                         };
+                        // Pastel shades for the dark background, deeper
+                        // ones so the text stays readable on the light one.
+                        let pick = |dark: egui::Color32, light: egui::Color32| {
+                            if app.dark_mode { dark } else { light }
+                        };
                         let color = if content.starts_with('✅') {
-                            egui::Color32::LIGHT_GREEN
+                            pick(
+                                egui::Color32::LIGHT_GREEN,
+                                egui::Color32::from_rgb(21, 128, 61),
+                            )
                         } else if content.starts_with('❌') {
-                            egui::Color32::LIGHT_RED
+                            pick(
+                                egui::Color32::LIGHT_RED,
+                                egui::Color32::from_rgb(185, 28, 28),
+                            )
                         } else if content.starts_with('⚠') {
-                            egui::Color32::YELLOW
+                            pick(egui::Color32::YELLOW, egui::Color32::from_rgb(161, 98, 7))
                         } else if content.starts_with('🎵') {
-                            egui::Color32::from_rgb(134, 239, 172)
+                            pick(
+                                egui::Color32::from_rgb(134, 239, 172),
+                                egui::Color32::from_rgb(22, 101, 52),
+                            )
                         } else if content.starts_with('🔍') {
-                            egui::Color32::from_rgb(147, 197, 253)
+                            pick(
+                                egui::Color32::from_rgb(147, 197, 253),
+                                egui::Color32::from_rgb(29, 78, 216),
+                            )
                         } else if content.starts_with('⏳') {
-                            egui::Color32::from_rgb(253, 224, 71)
+                            pick(
+                                egui::Color32::from_rgb(253, 224, 71),
+                                egui::Color32::from_rgb(133, 77, 14),
+                            )
                         } else if content.starts_with('🗑') {
-                            egui::Color32::from_rgb(200, 200, 200)
+                            pick(
+                                egui::Color32::from_rgb(200, 200, 200),
+                                egui::Color32::from_rgb(82, 82, 91),
+                            )
                         } else if content.starts_with('🚀') {
-                            egui::Color32::from_rgb(167, 139, 250)
-                        } else if app.dark_mode {
-                            egui::Color32::GRAY
+                            pick(
+                                egui::Color32::from_rgb(167, 139, 250),
+                                egui::Color32::from_rgb(109, 40, 217),
+                            )
                         } else {
-                            egui::Color32::from_gray(100)
+                            muted_text(app.dark_mode)
                         };
                         ui.label(egui::RichText::new(line).color(color).small());
                     }
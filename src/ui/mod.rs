@@ -2,13 +2,20 @@ pub mod display_tab;
 pub mod helpers;
 pub mod log_tab;
 pub mod monitor_preview;
+pub mod quick_move;
 pub mod settings_tab;
 pub mod warp_tab;
 
+use std::sync::Arc;
+
 use eframe::egui;
 use egui_phosphor::regular;
 
 use crate::app::{AppTab, WindowManagerApp};
+use crate::models::{CloseAction, ThemeMode};
+
+/// Mirrors `SavedData::high_contrast` for helpers that only get `dark_mode`.
+pub static HIGH_CONTRAST: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
 
 // ─── eframe App impl ─────────────────────────────────────────────────────────
 
@@ -19,24 +26,309 @@ impl eframe::App for WindowManagerApp {
         // ── First Frame Hidden Override ────────────────────────────────────
         if !self.first_frame_hidden {
             self.first_frame_hidden = true;
-            if self.data.lock().start_minimized {
-                hide_native_window(ctx);
+            if self.data.lock().start_minimized || WindowManagerApp::minimized_flag() {
+                hide_native_window();
+            }
+        }
+
+        // ── Drag-and-drop an exe / shortcut to start a new profile ─────────
+        let dropped = ctx.input(|i| i.raw.dropped_files.clone());
+        if let Some(path) = dropped.into_iter().find_map(|f| f.path) {
+            self.handle_dropped_file(path);
+        }
+
+        // ── Persist changes made by background threads ──────────────────
+        if crate::app::CONFIG_DIRTY.swap(false, std::sync::atomic::Ordering::Relaxed) {
+            self.save_data();
+        }
+
+        // ── Monitor hotplug ────────────────────────────────────────────
+        if crate::monitor::DISPLAY_CHANGED.swap(false, std::sync::atomic::Ordering::Relaxed) {
+            let settle = std::time::Duration::from_millis(750);
+            self.display_refresh_at = Some(std::time::Instant::now() + settle);
+            ctx.request_repaint_after(settle);
+        }
+        if self
+            .display_refresh_at
+            .is_some_and(|at| std::time::Instant::now() >= at)
+        {
+            self.display_refresh_at = None;
+            self.handle_display_change();
+        }
+
+        // ── Audio device hotplug ───────────────────────────────────────
+        if crate::audio::AUDIO_DEVICES_CHANGED.swap(false, std::sync::atomic::Ordering::Relaxed) {
+            self.refresh_audio_devices();
+        }
+
+        // ── Detect external config edits ───────────────────────────────
+        if !self.config_conflict
+            && self.last_config_check.elapsed() >= std::time::Duration::from_secs(1)
+        {
+            self.last_config_check = std::time::Instant::now();
+            self.config_conflict = self.config_changed_externally();
+        }
+        if self.config_conflict {
+            egui::Window::new(format!("{} Config changed on disk", regular::WARNING))
+                .collapsible(false)
+                .resizable(false)
+                .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+                .show(ctx, |ui| {
+                    ui.label("monitor_config.json was modified outside DisplayWarp.");
+                    ui.label(
+                        egui::RichText::new(
+                            "Reload it, or keep the profiles shown here and overwrite it.",
+                        )
+                        .small(),
+                    );
+                    ui.add_space(8.0);
+                    ui.horizontal(|ui| {
+                        if ui
+                            .button(format!("{} Reload", regular::ARROW_CLOCKWISE))
+                            .clicked()
+                        {
+                            self.reload_data();
+                            self.config_conflict = false;
+                        }
+                        if ui
+                            .button(format!("{} Overwrite", regular::FLOPPY_DISK))
+                            .clicked()
+                        {
+                            self.overwrite_config();
+                            self.config_conflict = false;
+                        }
+                    });
+                });
+        }
+
+        // ── Primary switch confirmation ────────────────────────────────
+        if let Some(deadline) = self.primary_revert_deadline {
+            let left = deadline.saturating_duration_since(std::time::Instant::now());
+            if left.is_zero() {
+                self.undo_primary_switch();
+            } else {
+                egui::Window::new(format!("{} Keep this display layout?", regular::MONITOR))
+                    .collapsible(false)
+                    .resizable(false)
+                    .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+                    .show(ctx, |ui| {
+                        ui.label(format!(
+                            "Reverting to the previous layout in {}s.",
+                            left.as_secs() + 1
+                        ));
+                        ui.add_space(8.0);
+                        ui.horizontal(|ui| {
+                            if ui.button(format!("{} Keep", regular::CHECK)).clicked() {
+                                self.primary_revert_deadline = None;
+                            }
+                            if ui
+                                .button(format!("{} Revert", regular::ARROW_COUNTER_CLOCKWISE))
+                                .clicked()
+                            {
+                                self.undo_primary_switch();
+                            }
+                        });
+                    });
+            }
+        }
+
+        // ── Delete profile confirmation ────────────────────────────────
+        if let Some(i) = self.pending_delete_idx {
+            // Bound first so the lock is released before `delete_profile`.
+            let name = self.data.lock().profiles.get(i).map(|p| p.name.clone());
+            match name {
+                Some(name) => {
+                    egui::Window::new(format!("{} Delete profile?", regular::TRASH))
+                        .collapsible(false)
+                        .resizable(false)
+                        .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+                        .show(ctx, |ui| {
+                            ui.label(format!("Delete '{name}'?"));
+                            ui.label(egui::RichText::new("This can't be undone.").small());
+                            ui.add_space(8.0);
+                            ui.horizontal(|ui| {
+                                if ui.button("Cancel").clicked() {
+                                    self.pending_delete_idx = None;
+                                }
+                                if ui
+                                    .button(
+                                        egui::RichText::new(format!("{} Delete", regular::TRASH))
+                                            .color(egui::Color32::from_rgb(220, 38, 38)),
+                                    )
+                                    .clicked()
+                                {
+                                    self.pending_delete_idx = None;
+                                    self.delete_profile(i);
+                                }
+                            });
+                        });
+                }
+                None => self.pending_delete_idx = None,
+            }
+        }
+
+        if let Some(i) = self.pending_relaunch_idx {
+            let profile = self.data.lock().profiles.get(i).cloned();
+            match profile {
+                Some(profile) => {
+                    egui::Window::new(format!(
+                        "{} Relaunch profile?",
+                        regular::ARROW_COUNTER_CLOCKWISE
+                    ))
+                    .collapsible(false)
+                    .resizable(false)
+                    .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+                    .show(ctx, |ui| {
+                        if profile.is_running {
+                            ui.label(format!(
+                                "Force-close '{}' and launch it again?",
+                                profile.name
+                            ));
+                            ui.label(
+                                egui::RichText::new("Unsaved work in the app will be lost.")
+                                    .small(),
+                            );
+                        } else {
+                            ui.label(format!("'{}' isn't running. Launch it?", profile.name));
+                        }
+                        ui.add_space(8.0);
+                        ui.horizontal(|ui| {
+                            if ui.button("Cancel").clicked() {
+                                self.pending_relaunch_idx = None;
+                            }
+                            if ui
+                                .button(
+                                    egui::RichText::new(format!(
+                                        "{} Relaunch",
+                                        regular::ARROW_COUNTER_CLOCKWISE
+                                    ))
+                                    .color(egui::Color32::from_rgb(220, 38, 38)),
+                                )
+                                .clicked()
+                            {
+                                self.pending_relaunch_idx = None;
+                                WindowManagerApp::relaunch_profile(
+                                    &profile,
+                                    Arc::clone(&self.data),
+                                    Arc::clone(&self.status_message),
+                                    Arc::clone(&self.status_log),
+                                );
+                            }
+                        });
+                    });
+                }
+                None => self.pending_relaunch_idx = None,
             }
         }
 
+        if self.show_disable_monitors_warning {
+            egui::Window::new(format!("{} Turn off other monitors?", regular::WARNING))
+                .collapsible(false)
+                .resizable(false)
+                .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+                .show(ctx, |ui| {
+                    ui.label("Every monitor except the profile's target will be switched off while the app runs.");
+                    ui.label("They come back when the app exits. If DisplayWarp is closed first, press Win+P or replug a monitor to get them back.");
+                    ui.add_space(8.0);
+                    ui.horizontal(|ui| {
+                        if ui.button("Cancel").clicked() {
+                            self.show_disable_monitors_warning = false;
+                        }
+                        if ui
+                            .button(
+                                egui::RichText::new(format!("{} Turn them off", regular::WARNING))
+                                    .color(egui::Color32::from_rgb(220, 38, 38)),
+                            )
+                            .clicked()
+                        {
+                            self.show_disable_monitors_warning = false;
+                            self.edit_profile_disable_others = true;
+                            self.data.lock().disable_monitors_confirmed = true;
+                            self.save_data();
+                        }
+                    });
+                });
+        }
+
+        // ── Quick-move popup (global hotkey) ───────────────────────────
+        quick_move::draw_quick_move(self, ctx);
+
         // ── Intercept close ────────────────────────────────────────────────
         let close_requested = ctx.input(|i| i.viewport().close_requested());
         if close_requested {
             ctx.send_viewport_cmd(egui::ViewportCommand::CancelClose);
 
-            if self.data.lock().close_to_tray {
-                hide_native_window(ctx);
-            } else {
-                self.watcher_running
-                    .store(false, std::sync::atomic::Ordering::Relaxed);
-                std::process::exit(0);
+            let action = self.data.lock().close_action;
+            match action {
+                CloseAction::AskEveryTime => {
+                    self.show_close_dialog = true;
+                    self.close_remember_choice = false;
+                }
+                CloseAction::MinimizeToTray => hide_native_window(),
+                CloseAction::Quit => {
+                    self.watcher_running
+                        .store(false, std::sync::atomic::Ordering::Relaxed);
+                    crate::file_log::flush();
+                    std::process::exit(0);
+                }
             }
         }
+        if self.show_close_dialog {
+            let mut chosen = None;
+            egui::Window::new(format!("{} Close DisplayWarp?", regular::X_CIRCLE))
+                .collapsible(false)
+                .resizable(false)
+                .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+                .show(ctx, |ui| {
+                    ui.label("Keep DisplayWarp running in the tray, or quit it?");
+                    ui.checkbox(&mut self.close_remember_choice, "Remember my choice")
+                        .on_hover_text("Change it later under Settings → Application Behavior");
+                    ui.add_space(8.0);
+                    ui.horizontal(|ui| {
+                        if ui
+                            .button(format!("{} Minimize to tray", regular::ARROW_LINE_DOWN))
+                            .clicked()
+                        {
+                            chosen = Some(CloseAction::MinimizeToTray);
+                        }
+                        if ui.button(format!("{} Quit", regular::POWER)).clicked() {
+                            chosen = Some(CloseAction::Quit);
+                        }
+                        if ui.button("Cancel").clicked() {
+                            self.show_close_dialog = false;
+                        }
+                    });
+                });
+            if let Some(action) = chosen {
+                self.show_close_dialog = false;
+                if self.close_remember_choice {
+                    self.data.lock().close_action = action;
+                    self.save_data();
+                }
+                if action == CloseAction::Quit {
+                    self.watcher_running
+                        .store(false, std::sync::atomic::Ordering::Relaxed);
+                    crate::file_log::flush();
+                    std::process::exit(0);
+                }
+                hide_native_window();
+            }
+        }
+
+        // ── Follow the Windows theme ───────────────────────────────────
+        // winit re-reads the system theme on WM_SETTINGCHANGE; the registry
+        // value is the fallback when it can't tell.
+        let (theme, high_contrast, font_scale) = {
+            let d = self.data.lock();
+            (d.theme, d.high_contrast, d.font_scale.clamp(0.75, 2.0))
+        };
+        HIGH_CONTRAST.store(high_contrast, std::sync::atomic::Ordering::Relaxed);
+        if theme == ThemeMode::Auto {
+            self.dark_mode = ctx
+                .system_theme()
+                .map(|t| t == egui::Theme::Dark)
+                .unwrap_or_else(crate::tray::is_dark_mode);
+        }
 
         // ── Apply theme styling ─────────────────────────────────────
         let mut style = (*ctx.style()).clone();
@@ -72,6 +364,51 @@ impl eframe::App for WindowManagerApp {
             style.visuals.widgets.active.bg_fill = egui::Color32::from_rgb(160, 175, 195);
         }
 
+        if high_contrast {
+            let (bg, fg, accent) = if self.dark_mode {
+                (
+                    egui::Color32::BLACK,
+                    egui::Color32::WHITE,
+                    egui::Color32::from_rgb(255, 214, 0),
+                )
+            } else {
+                (
+                    egui::Color32::WHITE,
+                    egui::Color32::BLACK,
+                    egui::Color32::from_rgb(0, 60, 200),
+                )
+            };
+            style.visuals.window_fill = bg;
+            style.visuals.panel_fill = bg;
+            style.visuals.extreme_bg_color = bg;
+            style.visuals.override_text_color = Some(fg);
+            for w in [
+                &mut style.visuals.widgets.noninteractive,
+                &mut style.visuals.widgets.inactive,
+                &mut style.visuals.widgets.hovered,
+                &mut style.visuals.widgets.active,
+                &mut style.visuals.widgets.open,
+            ] {
+                w.fg_stroke = egui::Stroke::new(1.5, fg);
+                w.bg_stroke = egui::Stroke::new(1.0, fg);
+            }
+            style.visuals.widgets.inactive.bg_fill = bg;
+            style.visuals.widgets.hovered.bg_stroke = egui::Stroke::new(2.0, accent);
+            style.visuals.widgets.active.bg_stroke = egui::Stroke::new(2.0, accent);
+            style.visuals.selection.bg_fill = accent;
+            style.visuals.selection.stroke = egui::Stroke::new(1.5, bg);
+        }
+
+        // Scale from egui's defaults so the factor doesn't compound per frame.
+        style.text_styles = egui::Style::default()
+            .text_styles
+            .into_iter()
+            .map(|(ts, mut font)| {
+                font.size *= font_scale;
+                (ts, font)
+            })
+            .collect();
+
         ctx.set_style(style);
 
         // ── Bottom Bar: Version + Theme Toggle ─────────────────────────────
@@ -91,14 +428,50 @@ impl eframe::App for WindowManagerApp {
                                             env!("CARGO_PKG_VERSION")
                                         ))
                                         .color(
-                                            if self.dark_mode {
-                                                egui::Color32::GRAY
-                                            } else {
-                                                egui::Color32::from_gray(100)
-                                            },
+                                            helpers::muted_text(self.dark_mode),
                                         ),
                                     );
 
+                                    // Startup health indicator
+                                    let healthy = self.health_issues.is_empty();
+                                    let (dot_rect, dot_resp) = ui.allocate_exact_size(
+                                        egui::vec2(10.0, 10.0),
+                                        egui::Sense::click(),
+                                    );
+                                    ui.painter().circle_filled(
+                                        dot_rect.center(),
+                                        4.0,
+                                        if healthy {
+                                            egui::Color32::from_rgb(34, 197, 94)
+                                        } else {
+                                            egui::Color32::from_rgb(239, 68, 68)
+                                        },
+                                    );
+                                    let dot_resp = if healthy {
+                                        dot_resp.on_hover_text("Health check passed")
+                                    } else {
+                                        dot_resp.on_hover_text(format!(
+                                            "Health check found problems (click for the log):\n• {}",
+                                            self.health_issues.join("\n• ")
+                                        ))
+                                    };
+                                    if dot_resp.clicked() {
+                                        self.current_tab = AppTab::Log;
+                                    }
+
+                                    if crate::elevation::is_elevated() {
+                                        ui.label(format!("{} Admin", regular::SHIELD_CHECK))
+                                            .on_hover_text("DisplayWarp is running as administrator");
+                                    } else {
+                                        ui.label(
+                                            egui::RichText::new(regular::SHIELD)
+                                                .color(helpers::muted_text(self.dark_mode)),
+                                        )
+                                        .on_hover_text(
+                                            "Not elevated: windows of apps running as administrator can't be moved",
+                                        );
+                                    }
+
                                     ui.with_layout(
                                         egui::Layout::right_to_left(egui::Align::Center),
                                         |ui| {
@@ -108,7 +481,11 @@ impl eframe::App for WindowManagerApp {
                                                 regular::MOON
                                             };
                                             if ui.button(theme_icon).clicked() {
-                                                self.dark_mode = !self.dark_mode;
+                                                self.set_theme(if self.dark_mode {
+                                                    ThemeMode::Light
+                                                } else {
+                                                    ThemeMode::Dark
+                                                });
                                             }
                                         },
                                     );
@@ -210,13 +587,7 @@ impl eframe::App for WindowManagerApp {
                                             "View background service activity and errors.",
                                         )
                                         .small()
-                                        .color(
-                                            if self.dark_mode {
-                                                egui::Color32::from_gray(140)
-                                            } else {
-                                                egui::Color32::from_gray(100)
-                                            },
-                                        ),
+                                        .color(helpers::muted_text(self.dark_mode)),
                                     );
                                     ui.add_space(8.0);
                                     log_tab::draw_status_bar(self, ui);
@@ -238,7 +609,7 @@ impl eframe::App for WindowManagerApp {
 
 // ─── Native window hide (for minimize-to-tray) ──────────────────────────────
 
-fn hide_native_window(_ctx: &egui::Context) {
+pub fn hide_native_window() {
     if let Some(hwnd) = get_eframe_hwnd() {
         unsafe {
             use windows::Win32::UI::WindowsAndMessaging::{
@@ -1,15 +1,22 @@
 use eframe::egui;
 use egui_phosphor::regular;
+use windows::Win32::Foundation::RECT;
 
 use crate::app::WindowManagerApp;
 
 // ─── Monitor Preview ─────────────────────────────────────────────────────────
 
+/// Draws the monitor layout, with `outline` (screen coordinates) marking
+/// where a window will land. Returns the index of a monitor the user clicked,
+/// either on the canvas or via the selector buttons; the caller decides what
+/// a click means.
 pub fn draw_monitor_preview(
     app: &mut WindowManagerApp,
     ui: &mut egui::Ui,
     highlight_idx: Option<usize>,
-) {
+    outline: Option<RECT>,
+) -> Option<usize> {
+    let mut clicked_idx = None;
     egui::Frame::group(ui.style())
         .inner_margin(egui::Margin::same(12))
         .corner_radius(egui::CornerRadius::same(8))
@@ -27,10 +34,14 @@ pub fn draw_monitor_preview(
             },
         ))
         .show(ui, |ui| {
-            let (rect, _) = ui.allocate_at_least(
+            let (rect, canvas_resp) = ui.allocate_at_least(
                 egui::vec2(ui.available_width(), 220.0),
-                egui::Sense::hover(),
+                egui::Sense::click(),
             );
+            let click_pos = canvas_resp
+                .clicked()
+                .then(|| canvas_resp.interact_pointer_pos())
+                .flatten();
             let painter = ui.painter_at(rect);
 
             // Draw background grid with fadeout at edges
@@ -129,21 +140,20 @@ pub fn draw_monitor_preview(
                 let height = (max_y - min_y) as f32;
                 let scale = (rect.width() / width).min(rect.height() / height) * 0.85;
                 let center = rect.center();
+                let to_canvas = |x: i32, y: i32| {
+                    center
+                        + egui::vec2(
+                            (x - min_x) as f32 * scale - (width * scale / 2.0),
+                            (y - min_y) as f32 * scale - (height * scale / 2.0),
+                        )
+                };
 
                 for (i, m) in app.monitors.iter().enumerate() {
                     let is_selected = highlight_idx == Some(i);
                     let is_primary = m.rect.left == 0 && m.rect.top == 0;
                     let m_rect = egui::Rect::from_min_max(
-                        center
-                            + egui::vec2(
-                                (m.rect.left - min_x) as f32 * scale - (width * scale / 2.0),
-                                (m.rect.top - min_y) as f32 * scale - (height * scale / 2.0),
-                            ),
-                        center
-                            + egui::vec2(
-                                (m.rect.right - min_x) as f32 * scale - (width * scale / 2.0),
-                                (m.rect.bottom - min_y) as f32 * scale - (height * scale / 2.0),
-                            ),
+                        to_canvas(m.rect.left, m.rect.top),
+                        to_canvas(m.rect.right, m.rect.bottom),
                     );
 
                     let fill = if is_selected {
@@ -166,6 +176,10 @@ pub fn draw_monitor_preview(
                         }
                     };
 
+                    if click_pos.is_some_and(|pos| m_rect.contains(pos)) {
+                        clicked_idx = Some(i);
+                    }
+
                     painter.rect_filled(m_rect, 4.0, fill);
                     painter.rect_stroke(
                         m_rect,
@@ -183,36 +197,49 @@ pub fn draw_monitor_preview(
 
                     let w = m.rect.right - m.rect.left;
                     let h = m.rect.bottom - m.rect.top;
+                    // Tiles in tall stacks get short; drop the secondary text
+                    // rather than let the labels pile on top of each other.
+                    let roomy = m_rect.height() >= 60.0 && m_rect.width() >= 70.0;
 
                     // Top Left: Coordinates
-                    painter.text(
-                        m_rect.min + egui::vec2(6.0, 6.0),
-                        egui::Align2::LEFT_TOP,
-                        format!("{}, {}", m.rect.left, m.rect.top),
-                        egui::FontId::proportional(10.0),
-                        if app.dark_mode {
-                            egui::Color32::from_white_alpha(140)
-                        } else {
-                            egui::Color32::from_black_alpha(140)
-                        },
-                    );
+                    if roomy {
+                        painter.text(
+                            m_rect.min + egui::vec2(6.0, 6.0),
+                            egui::Align2::LEFT_TOP,
+                            format!("{}, {}", m.rect.left, m.rect.top),
+                            egui::FontId::proportional(10.0),
+                            if app.dark_mode {
+                                egui::Color32::from_white_alpha(140)
+                            } else {
+                                egui::Color32::from_black_alpha(140)
+                            },
+                        );
+                    }
 
-                    // Center: "Resolution" label
-                    painter.text(
-                        m_rect.center() + egui::vec2(0.0, -8.0),
-                        egui::Align2::CENTER_CENTER,
-                        "Resolution",
-                        egui::FontId::proportional(10.0),
-                        if app.dark_mode {
-                            egui::Color32::from_white_alpha(180)
-                        } else {
-                            egui::Color32::from_black_alpha(180)
-                        },
-                    );
+                    // Center: user label, or "Resolution" when none is set
+                    let caption = app
+                        .data
+                        .lock()
+                        .monitor_label(m)
+                        .cloned()
+                        .unwrap_or_else(|| "Resolution".to_string());
+                    if roomy {
+                        painter.text(
+                            m_rect.center() + egui::vec2(0.0, -8.0),
+                            egui::Align2::CENTER_CENTER,
+                            caption,
+                            egui::FontId::proportional(10.0),
+                            if app.dark_mode {
+                                egui::Color32::from_white_alpha(180)
+                            } else {
+                                egui::Color32::from_black_alpha(180)
+                            },
+                        );
+                    }
 
-                    // Below Center: "Width x Height"
+                    // Center (below the caption when there is room): "Width x Height"
                     painter.text(
-                        m_rect.center() + egui::vec2(0.0, 6.0),
+                        m_rect.center() + egui::vec2(0.0, if roomy { 6.0 } else { 0.0 }),
                         egui::Align2::CENTER_CENTER,
                         format!("{}×{}", w, h),
                         egui::FontId::proportional(11.0),
@@ -236,10 +263,33 @@ pub fn draw_monitor_preview(
                         },
                     );
                 }
+
+                // Where the window will land, over the monitor tiles.
+                if let Some(r) = outline {
+                    let w_rect = egui::Rect::from_min_max(
+                        to_canvas(r.left, r.top),
+                        to_canvas(r.right, r.bottom),
+                    )
+                    .shrink(2.0);
+                    let color = egui::Color32::from_rgb(250, 204, 21);
+                    painter.rect_filled(w_rect, 2.0, color.gamma_multiply(0.18));
+                    painter.rect_stroke(
+                        w_rect,
+                        egui::CornerRadius::same(2),
+                        egui::Stroke::new(1.5, color),
+                        egui::StrokeKind::Inside,
+                    );
+                }
             }
 
-            // Legend row
+            // Legend row (below the canvas, so it never covers a monitor)
             ui.horizontal(|ui| {
+                if ui
+                    .button(format!("{} Refresh Monitor", regular::ARROW_CLOCKWISE))
+                    .clicked()
+                {
+                    app.refresh_monitors();
+                }
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                     ui.label(egui::RichText::new("Primary Monitor").size(12.0));
                     let (dot_rect2, _) =
@@ -271,13 +321,13 @@ pub fn draw_monitor_preview(
                 });
             });
 
-            // Monitor selector buttons
-            ui.horizontal(|ui| {
+            // Monitor selector buttons, wrapping onto extra rows when needed
+            ui.horizontal_wrapped(|ui| {
                 for (i, m) in app.monitors.iter().enumerate() {
                     let w = m.rect.right - m.rect.left;
                     let h = m.rect.bottom - m.rect.top;
-                    let label = format!("Monitor {} ({}×{})", i + 1, w, h);
-                    let is_selected = i == app.selected_mon_idx;
+                    let label = format!("{} ({}×{})", app.monitor_label(i), w, h);
+                    let is_selected = highlight_idx.unwrap_or(app.selected_mon_idx) == i;
 
                     let btn = if is_selected {
                         egui::Button::new(egui::RichText::new(&label).color(if app.dark_mode {
@@ -295,18 +345,69 @@ pub fn draw_monitor_preview(
                     };
 
                     if ui.add(btn).clicked() {
-                        app.selected_mon_idx = i;
+                        clicked_idx = Some(i);
                     }
                 }
 
-                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                    if ui
-                        .button(format!("{} Refresh Monitor", regular::ARROW_CLOCKWISE))
+                let rename_idx = highlight_idx.unwrap_or(app.selected_mon_idx);
+                if rename_idx < app.monitors.len()
+                    && app.renaming_monitor.is_none()
+                    && ui
+                        .small_button(regular::PENCIL_SIMPLE)
+                        .on_hover_text("Name this monitor")
                         .clicked()
-                    {
-                        app.refresh_monitors();
-                    }
-                });
+                {
+                    let current = app
+                        .data
+                        .lock()
+                        .monitor_label(&app.monitors[rename_idx])
+                        .cloned()
+                        .unwrap_or_default();
+                    app.renaming_monitor = Some((rename_idx, current));
+                }
             });
+
+            draw_monitor_rename(app, ui);
         });
+
+    clicked_idx
+}
+
+/// Inline "name this monitor" row, shown after the pencil button is clicked.
+fn draw_monitor_rename(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
+    let Some((idx, mut text)) = app.renaming_monitor.take() else {
+        return;
+    };
+    let Some(monitor) = app.monitors.get(idx).cloned() else {
+        return;
+    };
+    let mut done = false;
+    ui.horizontal(|ui| {
+        ui.label(format!(
+            "{} Name for monitor {}:",
+            regular::PENCIL_SIMPLE,
+            idx + 1
+        ));
+        let resp = ui.add(
+            egui::TextEdit::singleline(&mut text)
+                .hint_text("e.g. Living Room TV")
+                .desired_width(180.0),
+        );
+        let entered = resp.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+        if entered || ui.button(format!("{} Save", regular::CHECK)).clicked() {
+            app.data.lock().set_monitor_label(&monitor, &text);
+            app.save_data();
+            done = true;
+        }
+        if ui
+            .small_button(regular::X)
+            .on_hover_text("Cancel")
+            .clicked()
+        {
+            done = true;
+        }
+    });
+    if !done {
+        app.renaming_monitor = Some((idx, text));
+    }
 }
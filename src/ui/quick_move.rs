@@ -0,0 +1,60 @@
+use eframe::egui;
+use egui_phosphor::regular;
+use std::sync::Arc;
+
+use crate::app::WindowManagerApp;
+use crate::ui::monitor_preview::draw_monitor_preview;
+
+// ─── Quick Move popup ────────────────────────────────────────────────────────
+
+/// Small always-on-top viewport summoned by the quick-move hotkey. Clicking a
+/// monitor sends the window that was focused when the hotkey was pressed there.
+pub fn draw_quick_move(app: &mut WindowManagerApp, ctx: &egui::Context) {
+    if let Some(hwnd_raw) = app.quick_move_pending.lock().take() {
+        app.quick_move_hwnd = Some(hwnd_raw);
+    }
+    let Some(hwnd_raw) = app.quick_move_hwnd else {
+        return;
+    };
+
+    ctx.show_viewport_immediate(
+        egui::ViewportId::from_hash_of("quick_move"),
+        egui::ViewportBuilder::default()
+            .with_title("DisplayWarp Quick Move")
+            .with_inner_size([460.0, 330.0])
+            .with_resizable(false)
+            .with_always_on_top()
+            .with_active(true),
+        |ctx, _class| {
+            let mut close =
+                ctx.input(|i| i.key_pressed(egui::Key::Escape) || i.viewport().close_requested());
+
+            egui::CentralPanel::default().show(ctx, |ui| {
+                ui.label(
+                    egui::RichText::new(format!(
+                        "{} Click a monitor to send the focused window there",
+                        regular::ARROWS_OUT_CARDINAL
+                    ))
+                    .strong(),
+                );
+                ui.add_space(4.0);
+                if let Some(idx) = draw_monitor_preview(app, ui, None, None)
+                    && let Some(mon) = app.monitors.get(idx)
+                {
+                    WindowManagerApp::move_live_window(
+                        windows::Win32::Foundation::HWND(hwnd_raw as *mut _),
+                        mon.rect,
+                        app.live_move_placement,
+                        Arc::clone(&app.status_message),
+                        Arc::clone(&app.status_log),
+                    );
+                    close = true;
+                }
+            });
+
+            if close {
+                app.quick_move_hwnd = None;
+            }
+        },
+    );
+}
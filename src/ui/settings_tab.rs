@@ -2,6 +2,8 @@ use eframe::egui;
 use egui_phosphor::regular;
 
 use crate::app::WindowManagerApp;
+use crate::models::ThemeMode;
+use crate::ui::helpers::muted_text;
 
 pub fn draw_settings_tab(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
     ui.columns(2, |cols| {
@@ -36,11 +38,7 @@ pub fn draw_settings_tab(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
                     ui.label(
                         egui::RichText::new("Configure DisplayWarp behavior and preferences.")
                             .small()
-                            .color(if app.dark_mode {
-                                egui::Color32::from_gray(140)
-                            } else {
-                                egui::Color32::from_gray(100)
-                            }),
+                            .color(muted_text(app.dark_mode)),
                     );
                     ui.add_space(16.0);
 
@@ -66,7 +64,7 @@ pub fn draw_settings_tab(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
                             ui.label(egui::RichText::new("Config Location").strong());
                             ui.add_space(8.0);
 
-                            let config_path = crate::app::WindowManagerApp::get_config_path();
+                            let config_path = app.config_path.clone();
 
                             egui::ScrollArea::horizontal()
                                 .id_salt("config_path_scroll")
@@ -92,29 +90,70 @@ pub fn draw_settings_tab(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
                                 }
                                 if ui.button(format!("{} Change", regular::PENCIL_SIMPLE)).clicked() {
                                     if let Some(folder) = rfd::FileDialog::new().pick_folder() {
-                                        let exe_dir = std::env::current_exe()
-                                            .unwrap_or_else(|_| std::path::PathBuf::from("."))
-                                            .parent()
-                                            .unwrap_or_else(|| std::path::Path::new("."))
-                                            .to_path_buf();
-
-                                        let new_config_file = folder.join("monitor_config.json");
+                                        app.relocate_config(&folder);
+                                    }
+                                }
+                            });
+                        });
 
-                                        if config_path.exists() && !new_config_file.exists() {
-                                            let _ = std::fs::copy(&config_path, &new_config_file);
-                                        }
+                    ui.add_space(8.0);
 
-                                        let _ = std::fs::write(
-                                            exe_dir.join("config_location.txt"),
-                                            folder.to_string_lossy().as_ref(),
-                                        );
+                    // Workspaces Card
+                    egui::Frame::group(ui.style())
+                        .inner_margin(egui::Margin::same(12))
+                        .corner_radius(egui::CornerRadius::same(8))
+                        .fill(if app.dark_mode {
+                            egui::Color32::from_rgb(34, 34, 34)
+                        } else {
+                            egui::Color32::from_rgb(241, 245, 249)
+                        })
+                        .stroke(egui::Stroke::new(
+                            1.0,
+                            if app.dark_mode {
+                                egui::Color32::from_rgb(44, 44, 44)
+                            } else {
+                                egui::Color32::from_rgb(226, 232, 240)
+                            },
+                        ))
+                        .show(ui, |ui| {
+                            ui.set_width(ui.available_width());
+                            ui.label(egui::RichText::new(format!("{} Workspace", regular::STACK)).strong());
+                            ui.add_space(4.0);
+                            ui.label(
+                                egui::RichText::new("Separate sets of profiles, e.g. for different desks or docks.")
+                                    .small()
+                                    .color(muted_text(app.dark_mode)),
+                            );
+                            ui.add_space(8.0);
 
-                                        crate::app::WindowManagerApp::push_status(
-                                            &app.status_message,
-                                            &app.status_log,
-                                            "📁 Config location updated.",
-                                        );
+                            let index = app.workspace_index.clone();
+                            let mut switch_to: Option<String> = None;
+                            egui::ComboBox::from_id_salt("workspace_combo")
+                                .selected_text(&index.active)
+                                .width(ui.available_width())
+                                .show_ui(ui, |ui| {
+                                    for w in &index.workspaces {
+                                        if ui.selectable_label(*w == index.active, w).clicked()
+                                            && *w != index.active
+                                        {
+                                            switch_to = Some(w.clone());
+                                        }
                                     }
+                                });
+                            if let Some(name) = switch_to {
+                                app.switch_workspace(&name);
+                            }
+
+                            ui.add_space(4.0);
+                            ui.horizontal(|ui| {
+                                ui.add(
+                                    egui::TextEdit::singleline(&mut app.new_workspace_name)
+                                        .hint_text("New workspace name")
+                                        .desired_width(ui.available_width() - 80.0),
+                                );
+                                if ui.button(format!("{} Add", regular::PLUS)).clicked() {
+                                    let name = std::mem::take(&mut app.new_workspace_name);
+                                    app.create_workspace(&name);
                                 }
                             });
                         });
@@ -144,13 +183,36 @@ pub fn draw_settings_tab(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
                                 ui.with_layout(egui::Layout::left_to_right(egui::Align::Center), |ui| {
                                     ui.label(egui::RichText::new("Theme:").strong());
                                     ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                                        let theme_icon = if app.dark_mode { regular::SUN } else { regular::MOON };
-                                        if ui.button(format!("{} Toggle Theme", theme_icon)).clicked() {
-                                            app.dark_mode = !app.dark_mode;
+                                        let mut theme = app.data.lock().theme;
+                                        let before = theme;
+                                        ui.selectable_value(&mut theme, ThemeMode::Auto, format!("{} Auto (follow Windows)", regular::DESKTOP));
+                                        ui.selectable_value(&mut theme, ThemeMode::Light, format!("{} Light", regular::SUN));
+                                        ui.selectable_value(&mut theme, ThemeMode::Dark, format!("{} Dark", regular::MOON));
+                                        if theme != before {
+                                            app.set_theme(theme);
                                         }
                                     });
                                 });
                             });
+
+                            ui.add_space(6.0);
+                            let mut data = app.data.lock();
+                            let hc = ui
+                                .checkbox(&mut data.high_contrast, "High contrast")
+                                .on_hover_text("Solid text colours and outlined controls for readability")
+                                .clicked();
+                            let scale = ui
+                                .add(
+                                    egui::Slider::new(&mut data.font_scale, 0.75..=2.0)
+                                        .text("Text size")
+                                        .fixed_decimals(2),
+                                )
+                                .on_hover_text("Scales every font in the window");
+                            // Save once the slider settles, not on every drag step.
+                            if hc || scale.drag_stopped() || (scale.changed() && !scale.dragged()) {
+                                drop(data);
+                                app.save_data();
+                            }
                         });
 
                     ui.add_space(8.0);
@@ -180,8 +242,26 @@ pub fn draw_settings_tab(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
                             let mut data = app.data.lock();
                             let mut dirty = false;
 
+                            ui.horizontal(|ui| {
+                                ui.label("When closing the window:");
+                                egui::ComboBox::from_id_salt("close_action_combo")
+                                    .selected_text(data.close_action.label())
+                                    .show_ui(ui, |ui| {
+                                        for action in crate::models::CloseAction::ALL {
+                                            dirty |= ui
+                                                .selectable_value(
+                                                    &mut data.close_action,
+                                                    action,
+                                                    action.label(),
+                                                )
+                                                .changed();
+                                        }
+                                    });
+                            });
+
+                            ui.add_space(4.0);
                             if ui
-                                .checkbox(&mut data.close_to_tray, "Minimize to tray on close")
+                                .checkbox(&mut data.start_minimized, "Start minimized to tray")
                                 .clicked()
                             {
                                 dirty = true;
@@ -189,28 +269,56 @@ pub fn draw_settings_tab(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
 
                             ui.add_space(4.0);
                             if ui
-                                .checkbox(&mut data.start_minimized, "Start minimized to tray")
+                                .checkbox(
+                                    &mut data.start_without_focus,
+                                    "Start without taking focus",
+                                )
+                                .on_hover_text("Opens behind the app you are using instead of in front of it")
                                 .clicked()
                             {
                                 dirty = true;
                             }
 
+                            ui.add_space(4.0);
+                            ui.horizontal(|ui| {
+                                ui.label("Move focused window to next monitor:");
+                                let resp = ui
+                                    .add(
+                                        egui::TextEdit::singleline(&mut data.next_monitor_hotkey)
+                                            .hint_text("e.g. Ctrl+Alt+N")
+                                            .desired_width(110.0),
+                                    )
+                                    .on_hover_text("Global hotkey; leave empty to turn it off");
+                                if resp.lost_focus() {
+                                    dirty = true;
+                                }
+                            });
+
                             ui.add_space(4.0);
                             if ui
-                                .checkbox(&mut data.start_on_boot, "Start on system startup")
+                                .checkbox(&mut data.start_on_boot, "Start with Windows")
+                                .on_hover_text("Starts DisplayWarp hidden in the tray when you log in")
                                 .clicked()
                             {
                                 dirty = true;
                                 let al = WindowManagerApp::get_auto_launch();
-                                if data.start_on_boot {
-                                    let _ = al.enable();
+                                let result = if data.start_on_boot {
+                                    al.enable()
                                 } else {
-                                    let _ = al.disable();
+                                    al.disable()
+                                };
+                                if let Err(e) = result {
+                                    data.start_on_boot = !data.start_on_boot;
+                                    crate::app::WindowManagerApp::push_status(
+                                        &app.status_message,
+                                        &app.status_log,
+                                        format!("❌ Could not update the startup entry: {e}"),
+                                    );
                                 }
                             }
 
+                            drop(data);
                             if dirty {
-                                drop(data);
                                 app.save_data();
                                 crate::app::WindowManagerApp::push_status(
                                     &app.status_message,
@@ -218,6 +326,30 @@ pub fn draw_settings_tab(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
                                     "⚙️ Application behavior settings updated.",
                                 );
                             }
+
+                            if !crate::elevation::is_elevated() {
+                                ui.add_space(4.0);
+                                if ui
+                                    .button(format!("{} Relaunch as administrator", regular::SHIELD_CHECK))
+                                    .on_hover_text("Needed to move windows of games that run as administrator")
+                                    .clicked()
+                                {
+                                    match crate::elevation::relaunch_elevated() {
+                                        Ok(()) => {
+                                            app.save_data();
+                                            app.watcher_running
+                                                .store(false, std::sync::atomic::Ordering::Relaxed);
+                                            crate::file_log::flush();
+                                            std::process::exit(0);
+                                        }
+                                        Err(e) => crate::app::WindowManagerApp::push_status(
+                                            &app.status_message,
+                                            &app.status_log,
+                                            format!("❌ Could not relaunch as administrator: {e}"),
+                                        ),
+                                    }
+                                }
+                            }
                         });
 
                     ui.add_space(8.0);
@@ -269,7 +401,7 @@ pub fn draw_settings_tab(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
                             ui.label(
                                 egui::RichText::new("Controls how often DisplayWarp scans application placement to enforce persistence rules. Lower speeds save CPU.")
                                     .size(11.0)
-                                    .color(egui::Color32::from_gray(140)),
+                                    .color(muted_text(app.dark_mode)),
                             );
                         });
 
@@ -299,7 +431,7 @@ pub fn draw_settings_tab(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
                             ui.label(
                                 egui::RichText::new("Export or import all profiles as JSON.")
                                     .small()
-                                    .color(egui::Color32::from_gray(140)),
+                                    .color(muted_text(app.dark_mode)),
                             );
                             ui.add_space(8.0);
                             ui.horizontal(|ui| {
@@ -326,36 +458,184 @@ pub fn draw_settings_tab(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
                                         .add_filter("JSON", &["json"])
                                         .pick_file()
                                     {
-                                        if let Ok(bytes) = std::fs::read(&path) {
-                                            if let Ok(imported) = serde_json::from_slice::<Vec<crate::models::AppProfile>>(&bytes) {
-                                                let mut d = app.data.lock();
-                                                let names: std::collections::HashSet<String> =
-                                                    d.profiles.iter().map(|p| p.name.clone()).collect();
-                                                let mut added = 0usize;
-                                                for p in imported {
-                                                    if !names.contains(&p.name) {
-                                                        d.profiles.push(p);
-                                                        added += 1;
-                                                    }
-                                                }
-                                                drop(d);
-                                                app.save_data();
-                                                crate::app::WindowManagerApp::push_status(
-                                                    &app.status_message,
-                                                    &app.status_log,
-                                                    format!("📦 Imported {added} profile(s)."),
-                                                );
+                                        // Accept a profile list or a whole config file.
+                                        let imported = std::fs::read(&path).ok().and_then(|bytes| {
+                                            serde_json::from_slice::<Vec<crate::models::AppProfile>>(&bytes)
+                                                .ok()
+                                                .or_else(|| {
+                                                    serde_json::from_slice::<crate::models::SavedData>(&bytes)
+                                                        .ok()
+                                                        .map(|d| d.profiles)
+                                                })
+                                        });
+                                        match imported {
+                                            Some(imported) => app.pending_profile_import = Some(imported),
+                                            None => crate::app::WindowManagerApp::push_status(
+                                                &app.status_message,
+                                                &app.status_log,
+                                                "❌ Import failed — invalid format.",
+                                            ),
+                                        }
+                                    }
+                                }
+                            });
+                            if let Some(count) = app.pending_profile_import.as_ref().map(|p| p.len()) {
+                                ui.add_space(4.0);
+                                ui.label(format!("{count} profile(s) read. Add them to yours or replace yours?"));
+                                ui.horizontal(|ui| {
+                                    let merge = ui
+                                        .button(format!("{} Merge", regular::PLUS))
+                                        .on_hover_text("Append, skipping names that already exist")
+                                        .clicked();
+                                    let replace = ui
+                                        .button(format!("{} Replace", regular::SWAP))
+                                        .on_hover_text("Delete all current profiles first")
+                                        .clicked();
+                                    if ui.button("Cancel").clicked() {
+                                        app.pending_profile_import = None;
+                                    }
+                                    if (merge || replace)
+                                        && let Some(imported) = app.pending_profile_import.take()
+                                    {
+                                        let mut d = app.data.lock();
+                                        if replace {
+                                            d.profiles.clear();
+                                        }
+                                        let mut added = 0usize;
+                                        for p in imported {
+                                            if !d.profiles.iter().any(|e| e.name == p.name) {
+                                                d.profiles.push(p);
+                                                added += 1;
+                                            }
+                                        }
+                                        drop(d);
+                                        app.save_data();
+                                        crate::app::WindowManagerApp::push_status(
+                                            &app.status_message,
+                                            &app.status_log,
+                                            format!("📦 Imported {added} profile(s)."),
+                                        );
+                                    }
+                                });
+                            }
+                            ui.add_space(4.0);
+                            if ui
+                                .button(format!("{} Import from other tool", regular::ARROW_SQUARE_IN))
+                                .on_hover_text("CSV or JSON with name, exe and monitor number (1-based)")
+                                .clicked()
+                                && let Some(path) = rfd::FileDialog::new()
+                                    .add_filter("CSV / JSON", &["csv", "json"])
+                                    .pick_file()
+                            {
+                                match crate::import::import_foreign_profiles(&path, &app.monitors) {
+                                    Ok((imported, mut problems)) => {
+                                        let mut d = app.data.lock();
+                                        let mut added = 0usize;
+                                        for p in imported {
+                                            if d.profiles.iter().any(|e| e.name == p.name) {
+                                                problems.push(format!("'{}' already exists.", p.name));
                                             } else {
-                                                crate::app::WindowManagerApp::push_status(
-                                                    &app.status_message,
-                                                    &app.status_log,
-                                                    "❌ Import failed — invalid format.",
-                                                );
+                                                d.profiles.push(p);
+                                                added += 1;
                                             }
                                         }
+                                        drop(d);
+                                        app.save_data();
+                                        for problem in &problems {
+                                            crate::app::WindowManagerApp::push_status(
+                                                &app.status_message,
+                                                &app.status_log,
+                                                format!("⚠️ Skipped: {problem}"),
+                                            );
+                                        }
+                                        crate::app::WindowManagerApp::push_status(
+                                            &app.status_message,
+                                            &app.status_log,
+                                            format!(
+                                                "📦 Imported {added} profile(s); {} row(s) skipped.",
+                                                problems.len()
+                                            ),
+                                        );
                                     }
+                                    Err(e) => crate::app::WindowManagerApp::push_status(
+                                        &app.status_message,
+                                        &app.status_log,
+                                        format!("❌ Import failed — {e}"),
+                                    ),
                                 }
-                            });
+                            }
+
+                            ui.add_space(4.0);
+                            egui::CollapsingHeader::new(format!("{} Fix paths after moving a library", regular::FOLDER_SIMPLE))
+                                .show(ui, |ui| {
+                                    egui::Grid::new("fix_paths_grid").num_columns(2).show(ui, |ui| {
+                                        ui.label("Replace:");
+                                        ui.add(egui::TextEdit::singleline(&mut app.fix_paths_from).hint_text(r"D:\Games"));
+                                        ui.end_row();
+                                        ui.label("With:");
+                                        ui.add(egui::TextEdit::singleline(&mut app.fix_paths_to).hint_text(r"E:\Games"));
+                                        ui.end_row();
+                                    });
+
+                                    let profiles = app.data.lock().profiles.clone();
+                                    let changes = crate::app::WindowManagerApp::rebase_exe_paths(
+                                        &profiles,
+                                        &app.fix_paths_from,
+                                        &app.fix_paths_to,
+                                    );
+                                    if changes.is_empty() {
+                                        ui.label(
+                                            egui::RichText::new("No profiles match that prefix.")
+                                                .small()
+                                                .color(muted_text(app.dark_mode)),
+                                        );
+                                        return;
+                                    }
+                                    let valid = changes.iter().filter(|(_, p)| p.exists()).count();
+                                    for (i, new_path) in &changes {
+                                        let (icon, color) = if new_path.exists() {
+                                            (regular::CHECK, egui::Color32::from_rgb(74, 222, 128))
+                                        } else {
+                                            (regular::X, egui::Color32::from_rgb(248, 113, 113))
+                                        };
+                                        ui.label(
+                                            egui::RichText::new(format!(
+                                                "{icon} {} → {}",
+                                                profiles[*i].name,
+                                                new_path.display()
+                                            ))
+                                            .small()
+                                            .color(color),
+                                        )
+                                        .on_hover_text(profiles[*i].exe_path.display().to_string());
+                                    }
+                                    ui.add_space(4.0);
+                                    if ui
+                                        .add_enabled(
+                                            valid > 0,
+                                            egui::Button::new(format!("{} Apply to {valid} profile(s)", regular::CHECK)),
+                                        )
+                                        .on_hover_text("Only paths that exist at the new location are changed")
+                                        .clicked()
+                                    {
+                                        let mut d = app.data.lock();
+                                        for (i, new_path) in changes.iter().filter(|(_, p)| p.exists()) {
+                                            if let Some(p) = d.profiles.get_mut(*i) {
+                                                p.exe_path = new_path.clone();
+                                            }
+                                        }
+                                        drop(d);
+                                        app.save_data();
+                                        crate::app::WindowManagerApp::push_status(
+                                            &app.status_message,
+                                            &app.status_log,
+                                            format!(
+                                                "🛠 Updated {valid} exe path(s); {} left unchanged.",
+                                                changes.len() - valid
+                                            ),
+                                        );
+                                    }
+                                });
                         });
                 });
         });
@@ -2,10 +2,11 @@ use std::sync::Arc;
 
 use eframe::egui;
 use egui_phosphor::regular;
+use windows::Win32::Foundation::RECT;
 
 use crate::app::WindowManagerApp;
-use crate::models::{AppProfile, SerializableRect};
-use crate::ui::helpers::truncate_text;
+use crate::models::{AppProfile, LaunchWatch, SerializableRect, WindowPlacementMode};
+use crate::ui::helpers::{muted_text, pick_executable, truncate_text};
 use crate::ui::monitor_preview::draw_monitor_preview;
 
 // ─── Saved Profiles List ─────────────────────────────────────────────────────
@@ -21,11 +22,7 @@ pub fn draw_profiles_list(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
     ui.label(
         egui::RichText::new("Launch or manage your saved application profiles.")
             .small()
-            .color(if app.dark_mode {
-                egui::Color32::from_gray(140)
-            } else {
-                egui::Color32::from_gray(100)
-            }),
+            .color(muted_text(app.dark_mode)),
     );
 
     ui.add_space(4.0);
@@ -33,17 +30,14 @@ pub fn draw_profiles_list(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
     let mut to_remove: Option<usize> = None;
     let mut to_move_up: Option<usize> = None;
     let mut to_move_down: Option<usize> = None;
+    let mut to_reorder: Option<(usize, usize)> = None;
     let profiles: Vec<AppProfile> = app.data.lock().profiles.clone();
 
     if profiles.is_empty() {
         ui.label(
             egui::RichText::new("No profiles yet — create one on the left.")
                 .small()
-                .color(if app.dark_mode {
-                    egui::Color32::GRAY
-                } else {
-                    egui::Color32::from_gray(100)
-                }),
+                .color(muted_text(app.dark_mode)),
         );
     }
 
@@ -62,48 +56,27 @@ pub fn draw_profiles_list(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
                 &mut to_remove,
                 &mut to_move_up,
                 &mut to_move_down,
+                &mut to_reorder,
             );
         }
     }
 
     if let Some(i) = to_move_up {
         if i > 0 {
-            app.data.lock().profiles.swap(i, i - 1);
-            app.save_data();
-            let profiles = app.data.lock().profiles.clone();
-            let display_profiles = app.data.lock().display_profiles.clone();
-            if let Some(t) = &app.tray {
-                t.refresh_menu(&profiles, &display_profiles);
-            }
+            app.move_profile(i, i - 1);
         }
     }
     if let Some(i) = to_move_down {
         if i < profiles_len - 1 {
-            app.data.lock().profiles.swap(i, i + 1);
-            app.save_data();
-            let profiles = app.data.lock().profiles.clone();
-            let display_profiles = app.data.lock().display_profiles.clone();
-            if let Some(t) = &app.tray {
-                t.refresh_menu(&profiles, &display_profiles);
-            }
+            app.move_profile(i, i + 1);
         }
     }
+    if let Some((from, to)) = to_reorder {
+        app.move_profile(from, to);
+    }
 
     if let Some(i) = to_remove {
-        let name = app.data.lock().profiles[i].name.clone();
-        app.data.lock().profiles.remove(i);
-        app.save_data();
-        // Refresh tray so deleted profile is removed from tray menu
-        let profiles = app.data.lock().profiles.clone();
-        let display_profiles = app.data.lock().display_profiles.clone();
-        if let Some(t) = &app.tray {
-            t.refresh_menu(&profiles, &display_profiles);
-        }
-        WindowManagerApp::push_status(
-            &app.status_message,
-            &app.status_log,
-            format!("🗑 Profile deleted: {}", name),
-        );
+        app.pending_delete_idx = Some(i);
     }
 }
 
@@ -118,8 +91,10 @@ fn draw_profile_card(
     to_remove: &mut Option<usize>,
     to_move_up: &mut Option<usize>,
     to_move_down: &mut Option<usize>,
+    to_reorder: &mut Option<(usize, usize)>,
 ) {
-    egui::Frame::group(ui.style())
+    let exe_missing = app.missing_exes.lock().contains(&p.exe_path);
+    let card = egui::Frame::group(ui.style())
         .inner_margin(egui::Margin::same(12))
         .corner_radius(egui::CornerRadius::same(8))
         .fill(if app.dark_mode {
@@ -127,17 +102,35 @@ fn draw_profile_card(
         } else {
             egui::Color32::from_rgb(241, 245, 249)
         })
-        .stroke(egui::Stroke::new(
-            1.0,
-            if app.dark_mode {
-                egui::Color32::from_rgb(44, 44, 44)
-            } else {
-                egui::Color32::from_rgb(226, 232, 240)
-            },
-        ))
+        .stroke(if exe_missing {
+            egui::Stroke::new(1.5, egui::Color32::from_rgb(220, 38, 38))
+        } else {
+            egui::Stroke::new(
+                1.0,
+                if app.dark_mode {
+                    egui::Color32::from_rgb(44, 44, 44)
+                } else {
+                    egui::Color32::from_rgb(226, 232, 240)
+                },
+            )
+        })
         .show(ui, |ui| {
             // ── Header: name + display badge (Vertical layout for narrow columns) ──
             ui.horizontal(|ui| {
+                // Only the handle starts a drag, so the card's buttons still click.
+                ui.dnd_drag_source(egui::Id::new(("profile_drag", i)), i, |ui| {
+                    ui.label(egui::RichText::new(regular::DOTS_SIX_VERTICAL).color(muted_text(app.dark_mode)));
+                })
+                .response
+                .on_hover_cursor(egui::CursorIcon::Grab)
+                .on_hover_text("Drag to reorder");
+                let (dot_color, dot_hover) = if p.is_running {
+                    (egui::Color32::from_rgb(34, 197, 94), "Running")
+                } else {
+                    (muted_text(app.dark_mode), "Not running")
+                };
+                ui.label(egui::RichText::new("●").color(dot_color).size(10.0))
+                    .on_hover_text(dot_hover);
                 ui.vertical(|ui| {
                     ui.label(egui::RichText::new(&p.name).strong().size(13.0));
                 });
@@ -164,16 +157,24 @@ fn draw_profile_card(
 
             ui.vertical(|ui| {
                 ui.horizontal_wrapped(|ui| {
-                    let hardware_name = app
-                        .display_targets
-                        .iter()
-                        .find(|t| t.device_name == p.target_monitor_name)
-                        .and_then(|t| t.hardware_name.clone())
-                        .unwrap_or_else(|| {
-                            p.target_monitor_name
-                                .replace("\\\\.\\", "")
-                                .replace("DISPLAY", "Display ")
-                        });
+                    let alias = {
+                        let data = app.data.lock();
+                        match WindowManagerApp::resolve_monitor(&app.monitors, p) {
+                            Some(m) => data.monitor_label(m).cloned(),
+                            None => data.monitor_labels.get(&p.target_monitor_name).cloned(),
+                        }
+                    };
+                    let hardware_name = alias.unwrap_or_else(|| {
+                        app.display_targets
+                            .iter()
+                            .find(|t| t.device_name == p.target_monitor_name)
+                            .and_then(|t| t.hardware_name.clone())
+                            .unwrap_or_else(|| {
+                                p.target_monitor_name
+                                    .replace("\\\\.\\", "")
+                                    .replace("DISPLAY", "Display ")
+                            })
+                    });
 
                     // Monitor Badge
                     egui::Frame::NONE
@@ -185,11 +186,15 @@ fn draw_profile_card(
                         .inner_margin(egui::Margin::symmetric(6, 2))
                         .corner_radius(egui::CornerRadius::same(6))
                         .show(ui, |ui| {
-                            let badge_text = format!(
-                                "{} {}",
-                                regular::MONITOR,
-                                truncate_text(&hardware_name, 15)
-                            );
+                            let badge_text = if p.follow_cursor {
+                                format!("{} Follows cursor", regular::CURSOR)
+                            } else {
+                                format!(
+                                    "{} {}",
+                                    regular::MONITOR,
+                                    truncate_text(&hardware_name, 15)
+                                )
+                            };
                             ui.label(egui::RichText::new(badge_text).small().color(
                                 if app.dark_mode {
                                     egui::Color32::from_rgb(150, 200, 255)
@@ -199,36 +204,95 @@ fn draw_profile_card(
                             ));
                         });
 
-                    if let Some(audio_id) = &p.target_audio_device_id {
-                        let audio_name = app
-                            .audio_devices
-                            .iter()
-                            .find(|d| d.id == *audio_id)
-                            .map(|d| d.name.clone())
-                            .unwrap_or_else(|| "Unknown Audio".to_string());
-
+                    // Typical after importing from another PC.
+                    let mut missing = vec![];
+                    if !p.follow_cursor
+                        && crate::app::WindowManagerApp::resolve_monitor(&app.monitors, p).is_none()
+                    {
+                        missing.push(format!("Monitor {} is not connected", p.target_monitor_name));
+                    }
+                    if exe_missing {
+                        missing.push(format!("{} does not exist", p.exe_path.display()));
+                    }
+                    if !missing.is_empty() {
                         egui::Frame::NONE
                             .fill(if app.dark_mode {
-                                egui::Color32::from_rgb(50, 40, 70)
+                                egui::Color32::from_rgb(70, 45, 30)
                             } else {
-                                egui::Color32::from_rgb(240, 230, 255)
+                                egui::Color32::from_rgb(255, 237, 213)
                             })
                             .inner_margin(egui::Margin::symmetric(6, 2))
                             .corner_radius(egui::CornerRadius::same(6))
                             .show(ui, |ui| {
-                                let badge_text = format!(
-                                    "{} {}",
-                                    regular::SPEAKER_HIGH,
-                                    truncate_text(&audio_name, 30)
-                                );
-
                                 ui.label(
-                                    egui::RichText::new(badge_text)
+                                    egui::RichText::new(format!("{} Check setup", regular::WARNING))
                                         .small()
-                                        .color(egui::Color32::from_rgb(167, 139, 250)),
-                                );
+                                        .color(if app.dark_mode {
+                                            egui::Color32::from_rgb(253, 186, 116)
+                                        } else {
+                                            egui::Color32::from_rgb(194, 65, 12)
+                                        }),
+                                )
+                                .on_hover_text(missing.join("\n"));
                             });
                     }
+
+                    if let Some(audio_id) = &p.target_audio_device_id {
+                        let audio_name = app
+                            .audio_devices
+                            .iter()
+                            .find(|d| d.id == *audio_id)
+                            .map(|d| d.name.clone());
+                        // An empty list means enumeration failed, not that the
+                        // device is gone.
+                        let disconnected = audio_name.is_none() && !app.audio_devices.is_empty();
+                        let (fill, color, badge_text) = if disconnected {
+                            (
+                                if app.dark_mode {
+                                    egui::Color32::from_rgb(70, 45, 30)
+                                } else {
+                                    egui::Color32::from_rgb(255, 237, 213)
+                                },
+                                if app.dark_mode {
+                                    egui::Color32::from_rgb(253, 186, 116)
+                                } else {
+                                    egui::Color32::from_rgb(194, 65, 12)
+                                },
+                                format!("{} Audio disconnected", regular::SPEAKER_SLASH),
+                            )
+                        } else {
+                            (
+                                if app.dark_mode {
+                                    egui::Color32::from_rgb(50, 40, 70)
+                                } else {
+                                    egui::Color32::from_rgb(240, 230, 255)
+                                },
+                                egui::Color32::from_rgb(167, 139, 250),
+                                format!(
+                                    "{} {}",
+                                    regular::SPEAKER_HIGH,
+                                    truncate_text(
+                                        audio_name.as_deref().unwrap_or("Unknown Audio"),
+                                        30
+                                    )
+                                ),
+                            )
+                        };
+
+                        let badge = egui::Frame::NONE
+                            .fill(fill)
+                            .inner_margin(egui::Margin::symmetric(6, 2))
+                            .corner_radius(egui::CornerRadius::same(6))
+                            .show(ui, |ui| {
+                                ui.label(egui::RichText::new(badge_text).small().color(color))
+                            })
+                            .inner;
+                        if disconnected {
+                            badge.on_hover_text(
+                                "Not connected. Launching uses a fallback or leaves audio unchanged.",
+                            );
+                        }
+                    }
                 });
             });
 
@@ -258,6 +322,63 @@ fn draw_profile_card(
                 );
             }
 
+            // ── Temporary enforcement pause (persistent profiles) ──
+            if p.persistent_monitor {
+                let remaining = p
+                    .enforce_paused_until
+                    .and_then(|until| until.checked_duration_since(std::time::Instant::now()));
+                ui.horizontal(|ui| {
+                    match remaining {
+                        Some(left) => {
+                            ui.label(
+                                egui::RichText::new(format!(
+                                    "{} Enforcement paused ({}m left)",
+                                    regular::PAUSE,
+                                    left.as_secs() / 60 + 1
+                                ))
+                                .small(),
+                            );
+                            if ui.small_button("Resume").clicked() {
+                                app.data.lock().profiles[i].enforce_paused_until = None;
+                            }
+                        }
+                        None => {
+                            if ui
+                                .small_button(format!("{} Pause 10 min", regular::PAUSE))
+                                .on_hover_text("Let the window move freely, then enforce again")
+                                .clicked()
+                            {
+                                app.data.lock().profiles[i].enforce_paused_until = Some(
+                                    std::time::Instant::now()
+                                        + std::time::Duration::from_secs(10 * 60),
+                                );
+                                WindowManagerApp::push_status(
+                                    &app.status_message,
+                                    &app.status_log,
+                                    format!("⏸ Paused enforcement for '{}' for 10 minutes.", p.name),
+                                );
+                            }
+                        }
+                    }
+                });
+            }
+
+            if p.borderless_fullscreen {
+                ui.horizontal(|ui| {
+                    if ui
+                        .small_button(format!("{} Restore window frame", regular::FRAME_CORNERS))
+                        .on_hover_text("Put the title bar and borders back on the running window")
+                        .clicked()
+                    {
+                        WindowManagerApp::restore_window_frame(
+                            p,
+                            &app.status_message,
+                            &app.status_log,
+                        );
+                    }
+                });
+            }
+
             // ── Persistent toggle ──
             // ui.horizontal(|ui| {
             //     let mut persistent = p.persistent_monitor;
@@ -269,9 +390,9 @@ fn draw_profile_card(
 
             ui.add_space(2.0);
 
-            // ── Action buttons: Launch | Edit | Delete ──
+            // ── Action buttons: Launch | Relaunch | Diagnose | Edit | Delete ──
             ui.horizontal(|ui| {
-                let btn_width = (ui.available_width() - 16.0) / 3.0;
+                let btn_width = (ui.available_width() - 32.0) / 5.0;
 
                 if ui
                     .add_sized(
@@ -282,6 +403,32 @@ fn draw_profile_card(
                 {
                     WindowManagerApp::launch_profile(
                         p,
+                        Arc::clone(&app.data),
+                        Arc::clone(&app.status_message),
+                        Arc::clone(&app.status_log),
+                    );
+                }
+                if ui
+                    .add_sized(
+                        [btn_width, 24.0],
+                        egui::Button::new(format!("{} Relaunch", regular::ARROW_COUNTER_CLOCKWISE)),
+                    )
+                    .on_hover_text("Close the running app and launch the profile again")
+                    .clicked()
+                {
+                    app.pending_relaunch_idx = Some(i);
+                }
+                if ui
+                    .add_sized(
+                        [btn_width, 24.0],
+                        egui::Button::new(format!("{} Diagnose", regular::WRENCH)),
+                    )
+                    .on_hover_text("Launch or find the window, move it, and report what happened in the Log tab")
+                    .clicked()
+                {
+                    WindowManagerApp::diagnose_profile(
+                        p,
+                        Arc::clone(&app.data),
                         Arc::clone(&app.status_message),
                         Arc::clone(&app.status_log),
                     );
@@ -296,16 +443,67 @@ fn draw_profile_card(
                     app.editing_profile_idx = Some(i);
                     app.edit_profile_name = p.name.clone();
                     app.edit_profile_exe = None;
-                    app.edit_profile_mon_idx = app
-                        .monitors
-                        .iter()
-                        .position(|m| m.device_name == p.target_monitor_name)
-                        .unwrap_or(0);
+                    app.edit_profile_mon_idx =
+                        crate::app::WindowManagerApp::resolve_monitor(&app.monitors, p)
+                            .and_then(|r| app.monitors.iter().position(|m| std::ptr::eq(m, r)))
+                            .unwrap_or(0);
                     app.edit_profile_window_process =
                         p.window_process_name.clone().unwrap_or_default();
                     app.edit_profile_launch_args = p.launch_args.clone().unwrap_or_default();
+                    app.edit_profile_working_dir = p
+                        .working_dir
+                        .as_ref()
+                        .map(|d| d.display().to_string())
+                        .unwrap_or_default();
+                    app.edit_profile_pre_launch_cmd = p.pre_launch_cmd.clone().unwrap_or_default();
+                    app.edit_profile_post_exit_cmd = p.post_exit_cmd.clone().unwrap_or_default();
                     app.edit_profile_window_title =
                         p.window_title_match.clone().unwrap_or_default();
+                    app.edit_profile_keep_attached = p.keep_attached;
+                    app.edit_profile_run_as_admin = p.run_as_admin;
+                    app.edit_profile_move_all_windows = p.move_all_windows;
+                    app.edit_profile_always_on_top = p.always_on_top;
+                    app.edit_profile_force_primary = p.force_primary;
+                    app.edit_profile_placement = p.placement;
+                    app.edit_profile_borderless = p.borderless_fullscreen;
+                    app.edit_profile_geometry =
+                        p.saved_placement.clone().map(|r| (r, p.saved_maximized));
+                    app.edit_profile_modes = None;
+                    app.edit_profile_display_mode =
+                        p.target_resolution
+                            .map(|(width, height)| crate::monitor::DisplayMode {
+                                width,
+                                height,
+                                hz: p.target_refresh_hz.unwrap_or(0),
+                            });
+                    app.edit_profile_scale_percent = p.target_scale_percent;
+                    app.edit_profile_enable_hdr = p.enable_hdr;
+                    app.edit_profile_disable_others = p.disable_other_monitors;
+                    app.edit_profile_hotkey = p.hotkey.clone().unwrap_or_default();
+                    app.recording_hotkey = false;
+                    app.edit_profile_blank_others = p.blank_other_monitors;
+                    app.edit_profile_follow_cursor = p.follow_cursor;
+                    app.edit_profile_hide_self = p.hide_self_on_launch;
+                    app.edit_profile_minimize_others = p.minimize_others_on_launch;
+                    app.edit_profile_restore_others = p.restore_others_on_exit;
+                    app.edit_profile_launch_watch = p.launch_watch;
+                    app.edit_profile_launch_watch_secs = p.launch_watch_secs;
+                    app.edit_profile_then_launch = p.then_launch.clone();
+                    app.edit_profile_window_timeout_secs = p.window_timeout_secs.unwrap_or(0);
+                    app.edit_profile_wait_for_path = p
+                        .wait_for_path
+                        .as_ref()
+                        .map(|w| w.display().to_string())
+                        .unwrap_or_default();
+                    app.edit_profile_audio_fallbacks = p.audio_device_preferences.clone();
+                    app.edit_profile_audio_settle_ms = p.audio_settle_ms;
+                    app.edit_profile_audio_input_idx = p
+                        .target_audio_input_id
+                        .as_ref()
+                        .and_then(|id| app.audio_input_devices.iter().position(|d| d.id == *id))
+                        .map_or(0, |i| i + 1);
+                    app.edit_profile_restore_audio = p.restore_audio_on_exit;
+                    app.edit_profile_volume = p.target_volume;
                     app.edit_profile_audio_device_idx = p
                         .target_audio_device_id
                         .as_ref()
@@ -323,7 +521,27 @@ fn draw_profile_card(
                     *to_remove = Some(i);
                 }
             });
-        });
+        })
+        .response;
+
+    // Dropping a dragged card here moves it into this card's slot.
+    if let Some(from) = card.dnd_hover_payload::<usize>()
+        && *from != i
+    {
+        let y = if *from < i {
+            card.rect.bottom()
+        } else {
+            card.rect.top()
+        };
+        ui.painter().hline(
+            card.rect.x_range(),
+            y,
+            egui::Stroke::new(2.0, egui::Color32::from_rgb(34, 197, 94)),
+        );
+    }
+    if let Some(from) = card.dnd_release_payload::<usize>() {
+        *to_reorder = Some((*from, i));
+    }
 
     ui.add_space(4.0);
 }
@@ -433,9 +651,12 @@ fn draw_edit_profile_form(
                                 .add(egui::Button::new(egui::RichText::new("Change").strong()))
                                 .clicked()
                             {
-                                app.edit_profile_exe = rfd::FileDialog::new()
-                                    .add_filter("Executable", &["exe"])
-                                    .pick_file();
+                                if let Some((path, args)) = pick_executable() {
+                                    app.edit_profile_exe = Some(path);
+                                    if !args.trim().is_empty() {
+                                        app.edit_profile_launch_args = args;
+                                    }
+                                }
                             }
                         });
                     });
@@ -470,7 +691,7 @@ fn draw_edit_profile_form(
                         .selected_text(if app.monitors.is_empty() {
                             "No monitors".to_string()
                         } else {
-                            format!("Monitor {}", app.edit_profile_mon_idx + 1)
+                            app.monitor_label(app.edit_profile_mon_idx)
                         })
                         .width(ui.available_width())
                         .show_ui(ui, |ui| {
@@ -490,10 +711,208 @@ fn draw_edit_profile_form(
                                 ui.selectable_value(
                                     &mut app.edit_profile_mon_idx,
                                     mi,
-                                    format!("Monitor {} ({}) ({}×{})", mi + 1, hardware_name, w, h),
+                                    format!("{} ({}) ({}×{})", app.monitor_label(mi), hardware_name, w, h),
                                 );
                             }
                         });
+                    ui.add_space(4.0);
+                    if ui
+                        .add_enabled(
+                            !app.monitors.is_empty(),
+                            egui::Button::new(format!("{} Preview placement", regular::EYE)),
+                        )
+                        .on_hover_text("Flash the target area on the actual monitor")
+                        .clicked()
+                    {
+                        if let Some(target_rect) = edit_placement_outline(app) {
+                            std::thread::spawn(move || {
+                                crate::window::flash_placement_rect(target_rect, 1000);
+                            });
+                        }
+                    }
+                    ui.checkbox(
+                        &mut app.edit_profile_follow_cursor,
+                        "Follow cursor (use the monitor under the mouse at launch)",
+                    );
+                    ui.horizontal(|ui| {
+                        ui.label("Display mode:");
+                        let describe = |m: &crate::monitor::DisplayMode| {
+                            if m.hz > 0 {
+                                format!("{}×{} @ {}Hz", m.width, m.height, m.hz)
+                            } else {
+                                format!("{}×{}", m.width, m.height)
+                            }
+                        };
+                        let device = app
+                            .monitors
+                            .get(app.edit_profile_mon_idx)
+                            .map(|m| m.device_name.clone());
+                        if let Some(device) = &device
+                            && app
+                                .edit_profile_modes
+                                .as_ref()
+                                .is_none_or(|(listed, _)| listed != device)
+                        {
+                            app.edit_profile_modes = Some((
+                                device.clone(),
+                                crate::monitor::list_display_modes(device),
+                            ));
+                        }
+                        let modes = match (&device, &app.edit_profile_modes) {
+                            (Some(device), Some((listed, modes))) if listed == device => {
+                                modes.clone()
+                            }
+                            _ => Vec::new(),
+                        };
+                        egui::ComboBox::from_id_salt(format!("edit_mode_{i}"))
+                            .selected_text(
+                                app.edit_profile_display_mode
+                                    .as_ref()
+                                    .map(describe)
+                                    .unwrap_or_else(|| "Don't change".to_string()),
+                            )
+                            .show_ui(ui, |ui| {
+                                ui.selectable_value(
+                                    &mut app.edit_profile_display_mode,
+                                    None,
+                                    "Don't change",
+                                );
+                                for mode in modes {
+                                    ui.selectable_value(
+                                        &mut app.edit_profile_display_mode,
+                                        Some(mode),
+                                        describe(&mode),
+                                    );
+                                }
+                            })
+                            .response
+                            .on_hover_text("Switched before launch and restored when the app exits");
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label("Scaling:");
+                        egui::ComboBox::from_id_salt(format!("edit_scale_{i}"))
+                            .selected_text(
+                                app.edit_profile_scale_percent
+                                    .map(|s| format!("{s}%"))
+                                    .unwrap_or_else(|| "Don't change".to_string()),
+                            )
+                            .show_ui(ui, |ui| {
+                                ui.selectable_value(
+                                    &mut app.edit_profile_scale_percent,
+                                    None,
+                                    "Don't change",
+                                );
+                                for step in crate::monitor::SCALE_STEPS {
+                                    ui.selectable_value(
+                                        &mut app.edit_profile_scale_percent,
+                                        Some(step),
+                                        format!("{step}%"),
+                                    );
+                                }
+                            })
+                            .response
+                            .on_hover_text("Set before launch and restored when the app exits. Uses an undocumented Windows API that some builds ignore");
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label("HDR:");
+                        let hdr_label = |v: Option<bool>| match v {
+                            None => "Don't change",
+                            Some(true) => "On",
+                            Some(false) => "Off",
+                        };
+                        egui::ComboBox::from_id_salt(format!("edit_hdr_{i}"))
+                            .selected_text(hdr_label(app.edit_profile_enable_hdr))
+                            .show_ui(ui, |ui| {
+                                for v in [None, Some(true), Some(false)] {
+                                    ui.selectable_value(
+                                        &mut app.edit_profile_enable_hdr,
+                                        v,
+                                        hdr_label(v),
+                                    );
+                                }
+                            })
+                            .response
+                            .on_hover_text("Switched before launch and restored when the app exits");
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label("Window size:");
+                        egui::ComboBox::from_id_salt(format!("edit_placement_{i}"))
+                            .selected_text(app.edit_profile_placement.label())
+                            .show_ui(ui, |ui| {
+                                for mode in WindowPlacementMode::ALL {
+                                    ui.selectable_value(
+                                        &mut app.edit_profile_placement,
+                                        mode,
+                                        mode.label(),
+                                    );
+                                }
+                            })
+                            .response
+                            .on_hover_text("Auto fills the monitor and keeps a maximized window maximized");
+                    });
+                    ui.checkbox(
+                        &mut app.edit_profile_borderless,
+                        "Borderless fullscreen (remove title bar and frame)",
+                    )
+                    .on_hover_text("For games stuck in windowed mode. Re-applied while the window is watched if the game puts its frame back.");
+                    ui.horizontal(|ui| {
+                        ui.label("Saved geometry:");
+                        match &app.edit_profile_geometry {
+                            Some((r, maximized)) => {
+                                ui.label(format!(
+                                    "{}×{} at ({}, {}){}",
+                                    r.right - r.left,
+                                    r.bottom - r.top,
+                                    r.left,
+                                    r.top,
+                                    if *maximized { ", maximized" } else { "" }
+                                ))
+                                .on_hover_text("Applied on launch instead of Window size");
+                                if ui
+                                    .small_button(regular::X)
+                                    .on_hover_text("Forget the saved geometry")
+                                    .clicked()
+                                {
+                                    app.edit_profile_geometry = None;
+                                }
+                            }
+                            None => {
+                                ui.label(egui::RichText::new("none").color(muted_text(app.dark_mode)));
+                            }
+                        }
+                        if ui
+                            .small_button(format!("{} Capture current window geometry", regular::CROP))
+                            .on_hover_text("Read the size and position of the running window")
+                            .clicked()
+                        {
+                            let proc_name = Some(app.edit_profile_window_process.trim().to_string())
+                                .filter(|s| !s.is_empty())
+                                .or_else(|| {
+                                    app.edit_profile_exe
+                                        .as_ref()
+                                        .unwrap_or(&p.exe_path)
+                                        .file_name()
+                                        .map(|n| n.to_string_lossy().into_owned())
+                                })
+                                .unwrap_or_default()
+                                .to_lowercase();
+                            match crate::window::find_window_by_process_name(
+                                &proc_name,
+                                p.window_title_match.as_deref(),
+                            )
+                            .and_then(crate::window::capture_window_geometry)
+                            {
+                                Some((rect, maximized)) => {
+                                    app.edit_profile_geometry = Some((rect.into(), maximized));
+                                }
+                                None => WindowManagerApp::push_status(
+                                    &app.status_message,
+                                    &app.status_log,
+                                    format!("❌ No running {proc_name} window to capture."),
+                                ),
+                            }
+                        }
+                    });
                 });
 
             ui.add_space(2.0);
@@ -557,9 +976,36 @@ fn draw_edit_profile_form(
                     ui.add_space(4.0);
                     ui.add(
                         egui::TextEdit::singleline(&mut app.edit_profile_window_title)
-                            .hint_text("Optional wildcard match (e.g. *Diablo*)")
+                            .hint_text("Optional title match (e.g. Diablo or *Diablo*)")
                             .desired_width(ui.available_width()),
                     );
+                    ui.add_space(4.0);
+                    ui.add(
+                        egui::TextEdit::singleline(&mut app.edit_profile_wait_for_path)
+                            .hint_text("Optional: wait for this file before placing")
+                            .desired_width(ui.available_width()),
+                    )
+                    .on_hover_text(
+                        "For games whose real window appears only after a lock file is written",
+                    );
+                    ui.add_space(4.0);
+                    ui.horizontal(|ui| {
+                        ui.label(egui::RichText::new("Window timeout:").small());
+                        ui.add(
+                            egui::DragValue::new(&mut app.edit_profile_window_timeout_secs)
+                                .range(0..=600)
+                                .custom_formatter(|n, _| {
+                                    if n == 0.0 {
+                                        "Default".to_string()
+                                    } else {
+                                        format!("{n} s")
+                                    }
+                                }),
+                        )
+                        .on_hover_text(
+                            "How long to look for the window. Default is 30 s by process name, 15 s by PID; raise it for slow launchers.",
+                        );
+                    });
                 });
 
             ui.add_space(2.0);
@@ -592,9 +1038,196 @@ fn draw_edit_profile_form(
                     ui.add_space(4.0);
                     ui.add(
                         egui::TextEdit::singleline(&mut app.edit_profile_launch_args)
-                            .hint_text("-opengl -windowed")
+                            .hint_text(r#"-windowed "C:\My Saves\file.sav""#)
+                            .desired_width(ui.available_width()),
+                    );
+                    ui.add_space(4.0);
+                    working_dir_picker(ui, &mut app.edit_profile_working_dir);
+                    ui.add_space(4.0);
+                    ui.add(
+                        egui::TextEdit::singleline(&mut app.edit_profile_pre_launch_cmd)
+                            .hint_text("Optional: command to run before launch")
                             .desired_width(ui.available_width()),
+                    )
+                    .on_hover_text("Runs through cmd /C in the working folder; the launch waits up to 60 s for it");
+                    ui.add_space(4.0);
+                    ui.add(
+                        egui::TextEdit::singleline(&mut app.edit_profile_post_exit_cmd)
+                            .hint_text("Optional: command to run after the app exits")
+                            .desired_width(ui.available_width()),
+                    )
+                    .on_hover_text("Runs through cmd /C in the working folder once the app has closed");
+                    ui.add_space(4.0);
+                    ui.horizontal(|ui| {
+                        ui.label("Hotkey:");
+                        if app.recording_hotkey {
+                            ui.label(egui::RichText::new("Press a combo…").italics());
+                            let combo = ui.input(|i| {
+                                i.events.iter().find_map(|e| match e {
+                                    egui::Event::Key {
+                                        key,
+                                        pressed: true,
+                                        modifiers,
+                                        ..
+                                    } => hotkey_combo(*key, *modifiers),
+                                    _ => None,
+                                })
+                            });
+                            if let Some(combo) = combo {
+                                app.edit_profile_hotkey = combo;
+                                app.recording_hotkey = false;
+                            }
+                            if ui.small_button("Cancel").clicked() {
+                                app.recording_hotkey = false;
+                            }
+                        } else {
+                            ui.label(if app.edit_profile_hotkey.is_empty() {
+                                "None".to_string()
+                            } else {
+                                app.edit_profile_hotkey.clone()
+                            });
+                            if ui
+                                .small_button(format!("{} Set Hotkey", regular::KEYBOARD))
+                                .on_hover_text("Launches this profile from anywhere, even with DisplayWarp hidden")
+                                .clicked()
+                            {
+                                app.recording_hotkey = true;
+                            }
+                            if !app.edit_profile_hotkey.is_empty()
+                                && ui.small_button(regular::X).on_hover_text("Clear hotkey").clicked()
+                            {
+                                app.edit_profile_hotkey.clear();
+                            }
+                        }
+                    });
+                    ui.checkbox(&mut app.edit_profile_force_primary, "Force Primary Monitor")
+                        .on_hover_text("For exclusive fullscreen games: makes the target monitor primary while the game runs, then restores the layout.");
+                    if ui
+                        .checkbox(
+                            &mut app.edit_profile_disable_others,
+                            "Turn off other monitors while running",
+                        )
+                        .on_hover_text("Stronger than Force Primary: every other monitor is switched off until the app exits.")
+                        .changed()
+                        && app.edit_profile_disable_others
+                        && !app.data.lock().disable_monitors_confirmed
+                    {
+                        // Held back until the one-time warning is accepted.
+                        app.edit_profile_disable_others = false;
+                        app.show_disable_monitors_warning = true;
+                    }
+                    ui.checkbox(
+                        &mut app.edit_profile_keep_attached,
+                        "Keep attached to DisplayWarp",
+                    )
+                    .on_hover_text(
+                        "Off by default: the app runs detached so closing DisplayWarp never closes it.",
+                    );
+                    ui.checkbox(
+                        &mut app.edit_profile_run_as_admin,
+                        format!("{} Run as administrator", regular::SHIELD_CHECK),
+                    )
+                    .on_hover_text("Launches through a UAC prompt, for games whose anti-cheat needs elevation. The window is matched by process name.");
+                    ui.checkbox(
+                        &mut app.edit_profile_move_all_windows,
+                        "Move all of the app's windows",
+                    )
+                    .on_hover_text("For browsers and Electron apps: tiles every top-level window on the target monitor");
+                    ui.checkbox(
+                        &mut app.edit_profile_always_on_top,
+                        format!("{} Always on top", regular::PUSH_PIN),
+                    )
+                    .on_hover_text("Keeps the window above other windows, including fullscreen games. Re-applied by the persistent watcher.");
+                    ui.checkbox(
+                        &mut app.edit_profile_blank_others,
+                        "Blank other monitors while running",
+                    )
+                    .on_hover_text("Covers every other screen in black until the window closes. Click a black screen to dismiss it.");
+                    ui.checkbox(
+                        &mut app.edit_profile_hide_self,
+                        "Hide DisplayWarp while it runs",
+                    )
+                    .on_hover_text("Hides to the tray after placing the window and comes back when the app exits");
+                    ui.checkbox(
+                        &mut app.edit_profile_minimize_others,
+                        "Minimize all other windows",
+                    )
+                    .on_hover_text("Leaves only this app (and DisplayWarp) on screen");
+                    ui.add_enabled(
+                        app.edit_profile_minimize_others,
+                        egui::Checkbox::new(
+                            &mut app.edit_profile_restore_others,
+                            "Restore them when it exits",
+                        ),
                     );
+                    ui.add_space(4.0);
+                    ui.horizontal(|ui| {
+                        ui.label("After placing:");
+                        ui.selectable_value(
+                            &mut app.edit_profile_launch_watch,
+                            LaunchWatch::OneShot,
+                            "Move once",
+                        )
+                        .on_hover_text("No follow-up. Pick this if the window flickers or you want to drag it away yourself.");
+                        ui.selectable_value(
+                            &mut app.edit_profile_launch_watch,
+                            LaunchWatch::Timed,
+                            "Watch for a while",
+                        )
+                        .on_hover_text("Moves it back if it jumps during startup, then stops. Covers games that reposition themselves while loading.");
+                        ui.selectable_value(
+                            &mut app.edit_profile_launch_watch,
+                            LaunchWatch::UntilClosed,
+                            "Watch until closed",
+                        )
+                        .on_hover_text("Keeps it on this monitor for the whole session. You can't move it elsewhere while it runs.");
+                    });
+                    if app.edit_profile_launch_watch == LaunchWatch::Timed {
+                        ui.horizontal(|ui| {
+                            ui.label("Watch for:");
+                            ui.add(
+                                egui::DragValue::new(&mut app.edit_profile_launch_watch_secs)
+                                    .range(0..=600)
+                                    .suffix(" s"),
+                            )
+                            .on_hover_text("0 places the window once");
+                        });
+                    }
+                    ui.add_space(4.0);
+                    ui.horizontal(|ui| {
+                        ui.label("Then launch:");
+                        let others: Vec<String> = app
+                            .data
+                            .lock()
+                            .profiles
+                            .iter()
+                            .filter(|o| o.name != p.name)
+                            .map(|o| o.name.clone())
+                            .collect();
+                        egui::ComboBox::from_id_salt(format!("edit_then_{i}"))
+                            .selected_text(
+                                app.edit_profile_then_launch
+                                    .clone()
+                                    .unwrap_or_else(|| "Nothing".to_string()),
+                            )
+                            .width(ui.available_width())
+                            .show_ui(ui, |ui| {
+                                ui.selectable_value(
+                                    &mut app.edit_profile_then_launch,
+                                    None,
+                                    "Nothing",
+                                );
+                                for name in others {
+                                    ui.selectable_value(
+                                        &mut app.edit_profile_then_launch,
+                                        Some(name.clone()),
+                                        name,
+                                    );
+                                }
+                            });
+                    })
+                    .response
+                    .on_hover_text("Launch another profile once this window is placed");
                 });
 
             ui.add_space(2.0);
@@ -629,14 +1262,16 @@ fn draw_edit_profile_form(
                         } else {
                             app.audio_devices
                                 .get(app.edit_profile_audio_device_idx - 1)
-                                .map(|d| truncate_text(&d.name, 25))
+                                .map(|d| {
+                                    audio_device_text(d, app.default_audio_device_id.as_deref(), 25)
+                                })
                                 .unwrap_or_else(|| "Default (System)".to_string())
                         };
 
                     ui.horizontal(|ui| {
                         egui::ComboBox::from_id_salt(format!("edit_audio_{i}"))
                             .selected_text(audio_text)
-                            .width(ui.available_width() - 60.0)
+                            .width(ui.available_width() - 90.0)
                             .show_ui(ui, |ui| {
                                 ui.selectable_value(
                                     &mut app.edit_profile_audio_device_idx,
@@ -644,7 +1279,11 @@ fn draw_edit_profile_form(
                                     "Default (System)",
                                 );
                                 for (di, d) in app.audio_devices.iter().enumerate() {
-                                    let item_text = truncate_text(&d.name, 40);
+                                    let item_text = audio_device_text(
+                                        d,
+                                        app.default_audio_device_id.as_deref(),
+                                        40,
+                                    );
                                     ui.selectable_value(
                                         &mut app.edit_profile_audio_device_idx,
                                         di + 1,
@@ -664,12 +1303,106 @@ fn draw_edit_profile_form(
                                 app.audio_devices.get(app.edit_profile_audio_device_idx - 1)
                             {
                                 let id = d.id.clone();
+                                let status = Arc::clone(&app.status_message);
+                                let log = Arc::clone(&app.status_log);
                                 std::thread::spawn(move || {
-                                    let _ = crate::audio::play_test_beep(&id);
+                                    if let Err(e) = crate::audio::play_test_beep(&id) {
+                                        WindowManagerApp::push_status(
+                                            &status,
+                                            &log,
+                                            format!("⚠️ Test sound failed: {}", e.message()),
+                                        );
+                                    }
                                 });
                             }
                         }
+                        if ui
+                            .small_button(regular::ARROWS_CLOCKWISE)
+                            .on_hover_text("Refresh audio devices")
+                            .clicked()
+                        {
+                            app.refresh_audio_devices();
+                        }
                     });
+
+                    // Fallbacks, tried in order when the device above is unplugged.
+                    if app.edit_profile_audio_device_idx > 0 {
+                        ui.add_space(4.0);
+                        ui.label(
+                            egui::RichText::new("Fallbacks when it is not connected:").small(),
+                        );
+                        let mut remove_fallback = None;
+                        for (fi, id) in app.edit_profile_audio_fallbacks.iter().enumerate() {
+                            let name = app
+                                .audio_devices
+                                .iter()
+                                .find(|d| d.id == *id)
+                                .map(|d| truncate_text(&d.name, 30))
+                                .unwrap_or_else(|| "Disconnected device".to_string());
+                            ui.horizontal(|ui| {
+                                ui.label(format!("{}. {}", fi + 1, name));
+                                if ui.small_button(regular::X).clicked() {
+                                    remove_fallback = Some(fi);
+                                }
+                            });
+                        }
+                        if let Some(fi) = remove_fallback {
+                            app.edit_profile_audio_fallbacks.remove(fi);
+                        }
+                        let mut add_fallback = None;
+                        egui::ComboBox::from_id_salt(format!("edit_audio_fallback_{i}"))
+                            .selected_text(format!("{} Add fallback", regular::PLUS))
+                            .width(ui.available_width())
+                            .show_ui(ui, |ui| {
+                                for d in &app.audio_devices {
+                                    if !app.edit_profile_audio_fallbacks.contains(&d.id)
+                                        && ui
+                                            .selectable_label(false, truncate_text(&d.name, 40))
+                                            .clicked()
+                                    {
+                                        add_fallback = Some(d.id.clone());
+                                    }
+                                }
+                            });
+                        if let Some(id) = add_fallback {
+                            app.edit_profile_audio_fallbacks.push(id);
+                        }
+                    }
+
+                    if app.edit_profile_audio_device_idx > 0 {
+                        ui.add_space(4.0);
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new("Settle delay:").small());
+                            ui.add(
+                                egui::DragValue::new(&mut app.edit_profile_audio_settle_ms)
+                                    .range(0..=5000)
+                                    .speed(10)
+                                    .suffix(" ms"),
+                            )
+                            .on_hover_text(
+                                "Wait this long after switching audio before looking for the window",
+                            );
+                        });
+                    }
+
+                    ui.add_space(4.0);
+                    audio_input_combo(
+                        ui,
+                        format!("edit_audio_input_{i}"),
+                        &app.audio_input_devices,
+                        &mut app.edit_profile_audio_input_idx,
+                    );
+                    volume_slider(ui, &mut app.edit_profile_volume);
+
+                    if app.edit_profile_audio_device_idx > 0
+                        || app.edit_profile_audio_input_idx > 0
+                        || app.edit_profile_volume.is_some()
+                    {
+                        ui.checkbox(
+                            &mut app.edit_profile_restore_audio,
+                            "Restore previous audio devices on exit",
+                        );
+                    }
                 });
 
             ui.add_space(4.0);
@@ -690,13 +1423,27 @@ fn draw_edit_profile_form(
                             return;
                         }
                         let mut data = app.data.lock();
+                        let name = app.edit_profile_name.trim().to_string();
+                        if data.profile_name_taken(&name, Some(idx)) {
+                            drop(data);
+                            WindowManagerApp::push_status(
+                                &app.status_message,
+                                &app.status_log,
+                                format!("❌ A profile named '{name}' already exists."),
+                            );
+                            return;
+                        }
+                        let hotkey_problem =
+                            crate::hotkey::hotkey_problem(&data, Some(idx), &app.edit_profile_hotkey)
+                                .map(|problem| format!("⚠️ '{name}': {problem}"));
+                        data.rename_profile(idx, name);
                         let prof = &mut data.profiles[idx];
-                        prof.name = app.edit_profile_name.trim().to_string();
                         if let Some(new_exe) = app.edit_profile_exe.take() {
                             prof.exe_path = new_exe;
                         }
                         let mon = &app.monitors[app.edit_profile_mon_idx];
                         prof.target_monitor_name = mon.device_name.clone();
+                        prof.target_monitor_id = mon.stable_id.clone();
                         prof.target_monitor_rect = Some(SerializableRect {
                             left: mon.rect.left,
                             top: mon.rect.top,
@@ -717,6 +1464,51 @@ fn draw_edit_profile_form(
                         } else {
                             Some(w_title)
                         };
+                        prof.keep_attached = app.edit_profile_keep_attached;
+                        prof.run_as_admin = app.edit_profile_run_as_admin;
+                        prof.move_all_windows = app.edit_profile_move_all_windows;
+                        prof.always_on_top = app.edit_profile_always_on_top;
+                        prof.force_primary = app.edit_profile_force_primary;
+                        prof.placement = app.edit_profile_placement;
+                        prof.borderless_fullscreen = app.edit_profile_borderless;
+                        prof.saved_maximized =
+                            app.edit_profile_geometry.as_ref().is_some_and(|g| g.1);
+                        prof.saved_placement = app.edit_profile_geometry.take().map(|g| g.0);
+                        prof.target_resolution = app
+                            .edit_profile_display_mode
+                            .map(|m| (m.width, m.height));
+                        prof.target_refresh_hz = app
+                            .edit_profile_display_mode
+                            .map(|m| m.hz)
+                            .filter(|hz| *hz > 0);
+                        prof.target_scale_percent = app.edit_profile_scale_percent;
+                        prof.enable_hdr = app.edit_profile_enable_hdr;
+                        prof.disable_other_monitors = app.edit_profile_disable_others;
+                        prof.hotkey = Some(app.edit_profile_hotkey.trim().to_string())
+                            .filter(|h| !h.is_empty());
+                        prof.blank_other_monitors = app.edit_profile_blank_others;
+                        prof.follow_cursor = app.edit_profile_follow_cursor;
+                        prof.hide_self_on_launch = app.edit_profile_hide_self;
+                        prof.minimize_others_on_launch = app.edit_profile_minimize_others;
+                        prof.restore_others_on_exit = app.edit_profile_restore_others;
+                        prof.launch_watch = app.edit_profile_launch_watch;
+                        prof.launch_watch_secs = app.edit_profile_launch_watch_secs;
+                        prof.then_launch = app.edit_profile_then_launch.clone();
+                        prof.window_timeout_secs = (app.edit_profile_window_timeout_secs > 0)
+                            .then_some(app.edit_profile_window_timeout_secs);
+                        let non_empty = |s: &str| {
+                            let s = s.trim();
+                            (!s.is_empty()).then(|| s.to_string())
+                        };
+                        prof.working_dir = non_empty_path(&app.edit_profile_working_dir);
+                        prof.pre_launch_cmd = non_empty(&app.edit_profile_pre_launch_cmd);
+                        prof.post_exit_cmd = non_empty(&app.edit_profile_post_exit_cmd);
+                        let wait_path = app.edit_profile_wait_for_path.trim();
+                        prof.wait_for_path = if wait_path.is_empty() {
+                            None
+                        } else {
+                            Some(std::path::PathBuf::from(wait_path))
+                        };
                         prof.target_audio_device_id = if app.edit_profile_audio_device_idx > 0
                             && (app.edit_profile_audio_device_idx - 1) < app.audio_devices.len()
                         {
@@ -728,25 +1520,41 @@ fn draw_edit_profile_form(
                         } else {
                             None
                         };
+                        // Fallbacks only make sense behind a specific device.
+                        prof.audio_device_preferences = if prof.target_audio_device_id.is_some() {
+                            app.edit_profile_audio_fallbacks.clone()
+                        } else {
+                            vec![]
+                        };
+                        prof.audio_settle_ms = app.edit_profile_audio_settle_ms;
+                        prof.target_audio_input_id = app
+                            .edit_profile_audio_input_idx
+                            .checked_sub(1)
+                            .and_then(|i| app.audio_input_devices.get(i))
+                            .map(|d| d.id.clone());
+                        prof.restore_audio_on_exit = app.edit_profile_restore_audio;
+                        prof.target_volume = app.edit_profile_volume;
                         drop(data);
                         app.save_data();
-                        // Refresh tray after profile edit
-                        let profiles = app.data.lock().profiles.clone();
-                        let display_profiles = app.data.lock().display_profiles.clone();
-                        if let Some(t) = &app.tray {
-                            t.refresh_menu(&profiles, &display_profiles);
-                        }
                         WindowManagerApp::push_status(
                             &app.status_message,
                             &app.status_log,
                             "📝 Profile saved.",
                         );
+                        if let Some(problem) = hotkey_problem {
+                            WindowManagerApp::push_status(
+                                &app.status_message,
+                                &app.status_log,
+                                problem,
+                            );
+                        }
                     }
                     app.editing_profile_idx = None;
                     app.edit_profile_exe = None;
                     app.edit_profile_window_process.clear();
                     app.edit_profile_launch_args.clear();
                     app.edit_profile_window_title.clear();
+                    app.edit_profile_wait_for_path.clear();
                 }
                 if ui
                     .add(egui::Button::new(format!("{} Cancel", regular::X)))
@@ -757,6 +1565,7 @@ fn draw_edit_profile_form(
                     app.edit_profile_window_process.clear();
                     app.edit_profile_launch_args.clear();
                     app.edit_profile_window_title.clear();
+                    app.edit_profile_wait_for_path.clear();
                 }
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                     if ui
@@ -764,7 +1573,6 @@ fn draw_edit_profile_form(
                         .clicked()
                     {
                         *to_remove = Some(i);
-                        app.editing_profile_idx = None;
                     }
                 });
             });
@@ -776,6 +1584,10 @@ fn draw_edit_profile_form(
 // ─── New Profile Form ────────────────────────────────────────────────────────
 
 pub fn draw_new_profile_form(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
+    if app.scroll_to_new_profile {
+        app.scroll_to_new_profile = false;
+        ui.scroll_to_cursor(Some(egui::Align::TOP));
+    }
     ui.horizontal(|ui| {
         ui.label(
             egui::RichText::new(format!("{} New Profile", regular::PLUS_CIRCLE))
@@ -786,11 +1598,7 @@ pub fn draw_new_profile_form(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
     ui.label(
         egui::RichText::new("Configure and save a new application launch profile.")
             .small()
-            .color(if app.dark_mode {
-                egui::Color32::from_gray(140)
-            } else {
-                egui::Color32::from_gray(100)
-            }),
+            .color(muted_text(app.dark_mode)),
     );
 
     ui.add_space(4.0);
@@ -832,15 +1640,15 @@ pub fn draw_new_profile_form(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
                         .button(egui::RichText::new("Select EXE").strong())
                         .clicked()
                     {
-                        if let Some(path) = rfd::FileDialog::new()
-                            .add_filter("Executable", &["exe"])
-                            .pick_file()
-                        {
+                        if let Some((path, args)) = pick_executable() {
                             app.new_profile_exe = Some(path.clone());
                             if app.new_profile_name.is_empty() {
                                 app.new_profile_name =
                                     path.file_name().unwrap().to_string_lossy().into_owned();
                             }
+                            if !args.trim().is_empty() {
+                                app.new_profile_launch_args = args;
+                            }
                         }
                     }
                 });
@@ -909,7 +1717,7 @@ pub fn draw_new_profile_form(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
                 .selected_text(if app.monitors.is_empty() {
                     "No monitors".to_string()
                 } else {
-                    format!("Monitor {}", app.selected_mon_idx + 1)
+                    app.monitor_label(app.selected_mon_idx)
                 })
                 .width(ui.available_width())
                 .show_ui(ui, |ui| {
@@ -929,10 +1737,23 @@ pub fn draw_new_profile_form(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
                         ui.selectable_value(
                             &mut app.selected_mon_idx,
                             i,
-                            format!("Monitor {} ({}) ({}×{})", i + 1, hardware_name, w, h),
+                            format!("{} ({}) ({}×{})", app.monitor_label(i), hardware_name, w, h),
                         );
                     }
                 });
+            ui.checkbox(
+                &mut app.new_profile_follow_cursor,
+                "Follow cursor (use the monitor under the mouse at launch)",
+            );
+            ui.horizontal(|ui| {
+                ui.label("Keep window in place for:");
+                ui.add(
+                    egui::DragValue::new(&mut app.new_profile_watch_secs)
+                        .range(0..=600)
+                        .suffix(" s"),
+                )
+                .on_hover_text("Moves the window back if it jumps after launch. 0 places it once.");
+            });
         });
 
     ui.add_space(2.0);
@@ -1020,7 +1841,7 @@ pub fn draw_new_profile_form(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
             ui.add_space(4.0);
             ui.add(
                 egui::TextEdit::singleline(&mut app.new_profile_window_title)
-                    .hint_text("Optional wildcard match (e.g. *Diablo*)")
+                    .hint_text("Optional title match (e.g. Diablo or *Diablo*)")
                     .desired_width(ui.available_width()),
             );
 
@@ -1033,9 +1854,11 @@ pub fn draw_new_profile_form(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
             ui.add_space(4.0);
             ui.add(
                 egui::TextEdit::singleline(&mut app.new_profile_launch_args)
-                    .hint_text("-opengl -windowed")
+                    .hint_text(r#"-windowed "C:\My Saves\file.sav""#)
                     .desired_width(ui.available_width()),
             );
+            ui.add_space(4.0);
+            working_dir_picker(ui, &mut app.new_profile_working_dir);
         });
 
     ui.add_space(2.0);
@@ -1070,14 +1893,14 @@ pub fn draw_new_profile_form(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
                 } else {
                     app.audio_devices
                         .get(app.new_profile_audio_device_idx - 1)
-                        .map(|d| truncate_text(&d.name, 25))
+                        .map(|d| audio_device_text(d, app.default_audio_device_id.as_deref(), 25))
                         .unwrap_or_else(|| "Default (System)".to_string())
                 };
 
             ui.horizontal(|ui| {
                 egui::ComboBox::from_id_salt("new_audio_switch")
                     .selected_text(audio_text)
-                    .width(ui.available_width() - 60.0)
+                    .width(ui.available_width() - 90.0)
                     .show_ui(ui, |ui| {
                         ui.selectable_value(
                             &mut app.new_profile_audio_device_idx,
@@ -1085,7 +1908,8 @@ pub fn draw_new_profile_form(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
                             "Default (System)",
                         );
                         for (di, d) in app.audio_devices.iter().enumerate() {
-                            let item_text = truncate_text(&d.name, 40);
+                            let item_text =
+                                audio_device_text(d, app.default_audio_device_id.as_deref(), 40);
                             ui.selectable_value(
                                 &mut app.new_profile_audio_device_idx,
                                 di + 1,
@@ -1103,12 +1927,36 @@ pub fn draw_new_profile_form(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
                 {
                     if let Some(d) = app.audio_devices.get(app.new_profile_audio_device_idx - 1) {
                         let id = d.id.clone();
+                        let status = Arc::clone(&app.status_message);
+                        let log = Arc::clone(&app.status_log);
                         std::thread::spawn(move || {
-                            let _ = crate::audio::play_test_beep(&id);
+                            if let Err(e) = crate::audio::play_test_beep(&id) {
+                                WindowManagerApp::push_status(
+                                    &status,
+                                    &log,
+                                    format!("⚠️ Test sound failed: {}", e.message()),
+                                );
+                            }
                         });
                     }
                 }
+                if ui
+                    .small_button(regular::ARROWS_CLOCKWISE)
+                    .on_hover_text("Refresh audio devices")
+                    .clicked()
+                {
+                    app.refresh_audio_devices();
+                }
             });
+
+            ui.add_space(4.0);
+            audio_input_combo(
+                ui,
+                "new_audio_input".to_string(),
+                &app.audio_input_devices,
+                &mut app.new_profile_audio_input_idx,
+            );
+            volume_slider(ui, &mut app.new_profile_volume);
         });
 
     ui.add_space(8.0);
@@ -1132,11 +1980,22 @@ pub fn draw_new_profile_form(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
             };
 
             let mut data = app.data.lock();
+            let name = app.new_profile_name.trim().to_string();
+            if data.profile_name_taken(&name, None) {
+                drop(data);
+                WindowManagerApp::push_status(
+                    &app.status_message,
+                    &app.status_log,
+                    format!("❌ A profile named '{name}' already exists."),
+                );
+                return;
+            }
             let proc = app.new_profile_window_process.trim().to_string();
             data.profiles.push(AppProfile {
-                name: app.new_profile_name.trim().to_string(),
+                name,
                 exe_path: app.new_profile_exe.clone().unwrap(),
                 target_monitor_name: pid_mon.device_name.clone(),
+                target_monitor_id: pid_mon.stable_id.clone(),
                 target_monitor_rect: Some(SerializableRect {
                     left: pid_mon.rect.left,
                     top: pid_mon.rect.top,
@@ -1149,13 +2008,25 @@ pub fn draw_new_profile_form(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
                 } else {
                     Some(app.new_profile_launch_args.trim().to_string())
                 },
+                working_dir: non_empty_path(&app.new_profile_working_dir),
                 window_title_match: if app.new_profile_window_title.trim().is_empty() {
                     None
                 } else {
                     Some(app.new_profile_window_title.trim().to_string())
                 },
-                force_primary: false,
-                persistent_monitor: false,
+                follow_cursor: app.new_profile_follow_cursor,
+                launch_watch: if app.new_profile_watch_secs == 0 {
+                    LaunchWatch::OneShot
+                } else {
+                    LaunchWatch::Timed
+                },
+                launch_watch_secs: app.new_profile_watch_secs,
+                target_volume: app.new_profile_volume,
+                target_audio_input_id: app
+                    .new_profile_audio_input_idx
+                    .checked_sub(1)
+                    .and_then(|i| app.audio_input_devices.get(i))
+                    .map(|d| d.id.clone()),
                 target_audio_device_id: if app.new_profile_audio_device_idx > 0
                     && (app.new_profile_audio_device_idx - 1) < app.audio_devices.len()
                 {
@@ -1167,6 +2038,7 @@ pub fn draw_new_profile_form(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
                 } else {
                     None
                 },
+                ..Default::default()
             });
             drop(data);
 
@@ -1174,15 +2046,14 @@ pub fn draw_new_profile_form(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
             app.new_profile_name.clear();
             app.new_profile_window_process.clear();
             app.new_profile_launch_args.clear();
+            app.new_profile_working_dir.clear();
             app.new_profile_window_title.clear();
+            app.new_profile_follow_cursor = false;
+            app.new_profile_watch_secs = 45;
             app.new_profile_audio_device_idx = 0;
+            app.new_profile_audio_input_idx = 0;
+            app.new_profile_volume = None;
             app.save_data();
-            // Refresh tray so new profile appears in tray menu
-            let profiles = app.data.lock().profiles.clone();
-            let display_profiles = app.data.lock().display_profiles.clone();
-            if let Some(t) = &app.tray {
-                t.refresh_menu(&profiles, &display_profiles);
-            }
             WindowManagerApp::push_status(
                 &app.status_message,
                 &app.status_log,
@@ -1203,13 +2074,12 @@ pub fn draw_live_process_mover(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
         );
     });
     ui.label(
-        egui::RichText::new("Easily move an open application to a specific monitor.")
-            .small()
-            .color(if app.dark_mode {
-                egui::Color32::from_gray(140)
-            } else {
-                egui::Color32::from_gray(100)
-            }),
+        egui::RichText::new(format!(
+            "Easily move an open application to a specific monitor. Press {} anywhere to quick-move the focused window.",
+            crate::hotkey::QUICK_MOVE_HOTKEY
+        ))
+        .small()
+            .color(muted_text(app.dark_mode)),
     );
 
     ui.add_space(4.0);
@@ -1232,29 +2102,48 @@ pub fn draw_live_process_mover(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
         ))
         .show(ui, |ui| {
             ui.set_width(ui.available_width());
-            ui.label(egui::RichText::new(format!("{} Window Process", regular::FILE)).strong());
+            ui.horizontal(|ui| {
+                ui.label(egui::RichText::new(format!("{} Window Process", regular::FILE)).strong());
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    let by_title = ui.selectable_label(app.live_sort_by_title, "Title");
+                    let by_exe = ui.selectable_label(!app.live_sort_by_title, "Exe");
+                    ui.label(
+                        egui::RichText::new("Sort:")
+                            .small()
+                            .color(muted_text(app.dark_mode)),
+                    );
+                    if by_title.clicked() || by_exe.clicked() {
+                        app.live_sort_by_title = by_title.clicked();
+                        app.sort_live_processes();
+                    }
+                });
+            });
             ui.add_space(4.0);
-            let current_label = app
-                .live_processes
-                .get(app.selected_live_process_idx)
-                .map(|e| e.label.as_str())
-                .unwrap_or("Select Live Process");
-            let display_label = truncate_text(current_label, 30);
+            // Truncate only the title so the exe name always stays readable.
+            let short_label = |e: &crate::window::ProcessEntry, max: usize| {
+                format!("{} — {}", e.exe_name, truncate_text(&e.title, max))
+            };
+            let current = app.live_processes.get(app.selected_live_process_idx);
+            let display_label = current
+                .map(|e| short_label(e, 24))
+                .unwrap_or_else(|| "Select Live Process".into());
+            let full_label = current.map(|e| e.label.clone());
             ui.add_enabled_ui(!app.live_processes.is_empty(), |ui| {
-                egui::ComboBox::from_id_salt("live_proc")
+                let combo = egui::ComboBox::from_id_salt("live_proc")
                     .selected_text(display_label)
                     .width(ui.available_width())
                     .height(300.0)
                     .show_ui(ui, |ui| {
                         for (i, entry) in app.live_processes.iter().enumerate() {
-                            let item_text = format!(
-                                "{} {}",
-                                regular::APP_WINDOW,
-                                truncate_text(&entry.label, 40)
-                            );
-                            ui.selectable_value(&mut app.selected_live_process_idx, i, item_text);
+                            let item_text =
+                                format!("{} {}", regular::APP_WINDOW, short_label(entry, 40));
+                            ui.selectable_value(&mut app.selected_live_process_idx, i, item_text)
+                                .on_hover_text(&entry.label);
                         }
                     });
+                if let Some(full) = full_label {
+                    combo.response.on_hover_text(full);
+                }
             });
         });
 
@@ -1287,7 +2176,7 @@ pub fn draw_live_process_mover(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
                 .selected_text(if app.monitors.is_empty() {
                     "No monitors".to_string()
                 } else {
-                    format!("Monitor {}", app.live_move_mon_idx + 1)
+                    app.monitor_label(app.live_move_mon_idx)
                 })
                 .width(ui.available_width())
                 .show_ui(ui, |ui| {
@@ -1307,14 +2196,60 @@ pub fn draw_live_process_mover(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
                         ui.selectable_value(
                             &mut app.live_move_mon_idx,
                             i,
-                            format!("Monitor {} ({}) ({}×{})", i + 1, hardware_name, w, h),
+                            format!("{} ({}) ({}×{})", app.monitor_label(i), hardware_name, w, h),
                         );
                     }
                 });
+            ui.add_space(4.0);
+            ui.horizontal(|ui| {
+                ui.label("Region:");
+                ui.add_enabled_ui(!app.live_move_all_windows, |ui| {
+                    egui::ComboBox::from_id_salt("live_placement")
+                        .selected_text(app.live_move_placement.label())
+                        .width(ui.available_width())
+                        .show_ui(ui, |ui| {
+                            for mode in WindowPlacementMode::ALL {
+                                ui.selectable_value(
+                                    &mut app.live_move_placement,
+                                    mode,
+                                    mode.label(),
+                                );
+                            }
+                        });
+                })
+                .response
+                .on_hover_text(
+                    "Send the window to a half or quarter of the monitor instead of filling it",
+                );
+            });
         });
 
     ui.add_space(6.0);
 
+    ui.horizontal(|ui| {
+        ui.checkbox(&mut app.live_move_all_windows, "All windows of this exe")
+            .on_hover_text("Move every window the process has open, tiled on the target monitor");
+        if let Some(entry) = app.live_processes.get(app.selected_live_process_idx) {
+            let on_top = crate::window::is_always_on_top(entry.hwnd);
+            if ui
+                .selectable_label(on_top, format!("{} On Top", regular::PUSH_PIN))
+                .on_hover_text("Keep the selected window above other windows")
+                .clicked()
+            {
+                let msg = if !crate::window::set_always_on_top(entry.hwnd, !on_top) {
+                    format!("❌ Could not change always-on-top for '{}'.", entry.label)
+                } else if on_top {
+                    format!("📌 '{}' is no longer on top.", entry.label)
+                } else {
+                    format!("📌 '{}' now stays on top.", entry.label)
+                };
+                WindowManagerApp::push_status(&app.status_message, &app.status_log, msg);
+            }
+        }
+    });
+
+    ui.add_space(6.0);
+
     // Move and Create Profile buttons
     let can_move = !app.live_processes.is_empty() && !app.monitors.is_empty();
 
@@ -1334,12 +2269,35 @@ pub fn draw_live_process_mover(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
             if let Some(entry) = app.live_processes.get(app.selected_live_process_idx) {
                 let hwnd = entry.hwnd;
                 let target = app.monitors[app.live_move_mon_idx].rect;
-                WindowManagerApp::move_live_window(
-                    hwnd,
-                    target.into(),
-                    Arc::clone(&app.status_message),
-                    Arc::clone(&app.status_log),
-                );
+                let exe_name = entry
+                    .exe_path
+                    .as_ref()
+                    .and_then(|p| p.file_name())
+                    .map(|n| n.to_string_lossy().to_string());
+                match exe_name {
+                    Some(exe_name) if app.live_move_all_windows => {
+                        let hwnds = crate::window::find_all_windows_by_process_name(
+                            &exe_name.to_lowercase(),
+                        );
+                        app.record_move_undo(&hwnds);
+                        WindowManagerApp::move_all_process_windows(
+                            exe_name,
+                            target,
+                            Arc::clone(&app.status_message),
+                            Arc::clone(&app.status_log),
+                        );
+                    }
+                    _ => {
+                        app.record_move_undo(&[hwnd]);
+                        WindowManagerApp::move_live_window(
+                            hwnd,
+                            target,
+                            app.live_move_placement,
+                            Arc::clone(&app.status_message),
+                            Arc::clone(&app.status_log),
+                        );
+                    }
+                }
             }
         }
 
@@ -1363,30 +2321,29 @@ pub fn draw_live_process_mover(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
                         return;
                     };
                     let exe = path.clone();
+                    let name = exe.file_name().unwrap().to_string_lossy().into_owned();
+                    if app.data.lock().profile_name_taken(&name, None) {
+                        WindowManagerApp::push_status(
+                            &app.status_message,
+                            &app.status_log,
+                            format!("❌ A profile named '{name}' already exists."),
+                        );
+                        return;
+                    }
                     app.data.lock().profiles.push(AppProfile {
-                        name: exe.file_name().unwrap().to_string_lossy().into_owned(),
+                        name,
                         exe_path: exe,
                         target_monitor_name: mon.device_name.clone(),
+                        target_monitor_id: mon.stable_id.clone(),
                         target_monitor_rect: Some(SerializableRect {
                             left: mon.rect.left,
                             top: mon.rect.top,
                             right: mon.rect.right,
                             bottom: mon.rect.bottom,
                         }),
-                        window_process_name: None,
-                        launch_args: None,
-                        window_title_match: None,
-                        force_primary: false,
-                        persistent_monitor: false,
-                        target_audio_device_id: None,
+                        ..Default::default()
                     });
                     app.save_data();
-                    // Refresh tray so quick-created profile appears in tray menu
-                    let profiles = app.data.lock().profiles.clone();
-                    let display_profiles = app.data.lock().display_profiles.clone();
-                    if let Some(t) = &app.tray {
-                        t.refresh_menu(&profiles, &display_profiles);
-                    }
                     WindowManagerApp::push_status(
                         &app.status_message,
                         &app.status_log,
@@ -1405,6 +2362,23 @@ pub fn draw_live_process_mover(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
     ui.with_layout(egui::Layout::top_down(egui::Align::Center), |ui| {
         let width = ui.available_width();
 
+        let undo_count = app.move_undo_stack.len();
+        if ui
+            .add_enabled_ui(undo_count > 0, |ui| {
+                ui.add_sized(
+                    [width, 30.0],
+                    egui::Button::new(format!("{} Undo Move", regular::ARROW_COUNTER_CLOCKWISE)),
+                )
+            })
+            .inner
+            .on_hover_text(format!(
+                "Put the last moved window back ({undo_count} saved)"
+            ))
+            .clicked()
+        {
+            app.undo_last_move();
+        }
+
         if ui
             .add_sized(
                 [width, 30.0],
@@ -1419,13 +2393,44 @@ pub fn draw_live_process_mover(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
 
 // ─── Main Warp Tab ──────────────────────────────────────────────────────────
 
+/// Where the edited profile's window will be placed, from its saved geometry
+/// or placement mode, for the preview outline and "Preview placement".
+fn edit_placement_outline(app: &WindowManagerApp) -> Option<RECT> {
+    let area = app.monitors.get(app.edit_profile_mon_idx)?.rect;
+    Some(match &app.edit_profile_geometry {
+        Some((normal, false)) => normal.to_rect(),
+        Some((_, true)) => area,
+        // The real size is only known once the window exists, so
+        // Centered is shown at two-thirds of the monitor.
+        None => {
+            let size = (
+                (area.right - area.left) * 2 / 3,
+                (area.bottom - area.top) * 2 / 3,
+            );
+            crate::window::placement_within(area, app.edit_profile_placement, size).unwrap_or(area)
+        }
+    })
+}
+
 pub fn draw_warp_tab(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
     let preview_idx = if app.editing_profile_idx.is_some() {
         app.edit_profile_mon_idx
     } else {
         app.selected_mon_idx
     };
-    draw_monitor_preview(app, ui, Some(preview_idx));
+    // While editing, outline where the profile's window will be placed.
+    let outline = app
+        .editing_profile_idx
+        .and_then(|_| edit_placement_outline(app));
+    if let Some(i) = draw_monitor_preview(app, ui, Some(preview_idx), outline) {
+        // While editing, a click retargets the edited profile and leaves the
+        // new-profile form's monitor alone.
+        if app.editing_profile_idx.is_some() {
+            app.edit_profile_mon_idx = i;
+        } else {
+            app.selected_mon_idx = i;
+        }
+    }
 
     ui.add_space(8.0);
 
@@ -1511,3 +2516,113 @@ pub fn draw_warp_tab(app: &mut WindowManagerApp, ui: &mut egui::Ui) {
         });
     });
 }
+
+/// Format a key press as a hotkey string ("Ctrl+Alt+1") if it has a modifier
+/// and a key `RegisterHotKey` can bind.
+fn hotkey_combo(key: egui::Key, modifiers: egui::Modifiers) -> Option<String> {
+    let mut parts = vec![];
+    if modifiers.ctrl {
+        parts.push("Ctrl");
+    }
+    if modifiers.alt {
+        parts.push("Alt");
+    }
+    if modifiers.shift {
+        parts.push("Shift");
+    }
+    let name = key.name();
+    let combo = format!("{}+{name}", parts.join("+"));
+    (!parts.is_empty() && crate::hotkey::parse_hotkey(&combo).is_some()).then_some(combo)
+}
+
+/// Microphone picker: `idx` 0 leaves the default capture device alone,
+/// otherwise it is 1 + an index into `devices`.
+fn audio_input_combo(
+    ui: &mut egui::Ui,
+    id_salt: String,
+    devices: &[crate::audio::AudioDeviceInfo],
+    idx: &mut usize,
+) {
+    let selected = idx
+        .checked_sub(1)
+        .and_then(|i| devices.get(i))
+        .map(|d| truncate_text(&d.name, 25))
+        .unwrap_or_else(|| "Don't change".to_string());
+    ui.horizontal(|ui| {
+        ui.label(format!("{} Microphone:", regular::MICROPHONE));
+        egui::ComboBox::from_id_salt(id_salt)
+            .selected_text(selected)
+            .width(ui.available_width())
+            .show_ui(ui, |ui| {
+                ui.selectable_value(idx, 0, "Don't change");
+                for (di, d) in devices.iter().enumerate() {
+                    ui.selectable_value(idx, di + 1, truncate_text(&d.name, 40));
+                }
+            });
+    });
+}
+
+/// Text field plus folder picker for a profile's working directory. Empty
+/// means the exe's folder.
+fn working_dir_picker(ui: &mut egui::Ui, dir: &mut String) {
+    ui.horizontal(|ui| {
+        ui.add(
+            egui::TextEdit::singleline(dir)
+                .hint_text("Working folder (default: the exe's folder)")
+                .desired_width(ui.available_width() - 36.0),
+        );
+        if ui
+            .button(regular::FOLDER_OPEN)
+            .on_hover_text("Pick the working folder")
+            .clicked()
+            && let Some(path) = rfd::FileDialog::new().pick_folder()
+        {
+            *dir = path.display().to_string();
+        }
+    });
+}
+
+/// `None` for a blank field, otherwise the trimmed path.
+fn non_empty_path(s: &str) -> Option<std::path::PathBuf> {
+    let s = s.trim();
+    (!s.is_empty()).then(|| std::path::PathBuf::from(s))
+}
+
+/// Combo text for an output device, marking the current system default.
+fn audio_device_text(
+    device: &crate::audio::AudioDeviceInfo,
+    default_id: Option<&str>,
+    max_len: usize,
+) -> String {
+    let name = truncate_text(&device.name, max_len);
+    if default_id == Some(device.id.as_str()) {
+        format!("{name} ● current")
+    } else {
+        name
+    }
+}
+
+/// Optional launch volume: a checkbox to opt in plus a 0–100% slider.
+fn volume_slider(ui: &mut egui::Ui, volume: &mut Option<f32>) {
+    ui.horizontal(|ui| {
+        let mut enabled = volume.is_some();
+        if ui
+            .checkbox(
+                &mut enabled,
+                format!("{} Set volume", regular::SPEAKER_HIGH),
+            )
+            .changed()
+        {
+            *volume = enabled.then_some(0.5);
+        }
+        if let Some(level) = volume {
+            let mut pct = (*level * 100.0).round();
+            if ui
+                .add(egui::Slider::new(&mut pct, 0.0..=100.0).suffix("%"))
+                .changed()
+            {
+                *level = pct / 100.0;
+            }
+        }
+    });
+}
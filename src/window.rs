@@ -1,29 +1,36 @@
 use std::ptr;
 use windows::Win32::Foundation::{HWND, LPARAM, POINT, RECT};
 use windows::Win32::Graphics::Gdi::{
-    HMONITOR, MONITOR_DEFAULTTONEAREST, MonitorFromPoint, MonitorFromWindow,
+    HMONITOR, MONITOR_DEFAULTTONEAREST, MONITOR_DEFAULTTONULL, MonitorFromPoint, MonitorFromWindow,
 };
 use windows::Win32::System::Threading::{
-    OpenProcess, PROCESS_NAME_WIN32, PROCESS_QUERY_LIMITED_INFORMATION, PROCESS_SYNCHRONIZE,
-    QueryFullProcessImageNameW, WaitForSingleObject,
+    GetProcessTimes, INFINITE, OpenProcess, PROCESS_NAME_WIN32, PROCESS_QUERY_LIMITED_INFORMATION,
+    PROCESS_SYNCHRONIZE, PROCESS_TERMINATE, QueryFullProcessImageNameW, TerminateProcess,
+    WaitForSingleObject,
 };
 use windows::Win32::UI::WindowsAndMessaging::{
-    BringWindowToTop, EnumWindows, GWL_EXSTYLE, GetWindowLongW, GetWindowPlacement, GetWindowRect,
-    GetWindowTextLengthW, GetWindowTextW, GetWindowThreadProcessId, HWND_TOP, IsWindow,
-    IsWindowVisible, SW_MAXIMIZE, SW_RESTORE, SW_SHOWMAXIMIZED, SWP_FRAMECHANGED, SWP_SHOWWINDOW,
-    SetForegroundWindow, SetWindowPlacement, SetWindowPos, ShowWindow, WINDOWPLACEMENT,
-    WS_EX_TOOLWINDOW,
+    BringWindowToTop, EnumWindows, GWL_EXSTYLE, GWL_STYLE, GetWindowLongW, GetWindowPlacement,
+    GetWindowRect, GetWindowTextLengthW, GetWindowTextW, GetWindowThreadProcessId, HWND_NOTOPMOST,
+    HWND_TOP, HWND_TOPMOST, IsIconic, IsWindow, IsWindowVisible, SW_MAXIMIZE, SW_MINIMIZE,
+    SW_RESTORE, SW_SHOWMAXIMIZED, SWP_FRAMECHANGED, SWP_NOACTIVATE, SWP_NOMOVE, SWP_NOSIZE,
+    SWP_NOZORDER, SWP_SHOWWINDOW, SetForegroundWindow, SetWindowLongW, SetWindowPlacement,
+    SetWindowPos, ShowWindow, WINDOWPLACEMENT, WS_CAPTION, WS_EX_CLIENTEDGE, WS_EX_DLGMODALFRAME,
+    WS_EX_STATICEDGE, WS_EX_TOOLWINDOW, WS_EX_TOPMOST, WS_EX_WINDOWEDGE, WS_MAXIMIZEBOX,
+    WS_MINIMIZEBOX, WS_SYSMENU, WS_THICKFRAME,
 };
 use windows::core::BOOL;
 
+use crate::models::WindowPlacementMode;
+
 // ─── Public types ─────────────────────────────────────────────────────────────
 
 #[derive(Clone)]
 pub struct ProcessEntry {
     pub hwnd: HWND,
-    #[allow(dead_code)]
     pub pid: u32,
     pub exe_path: Option<std::path::PathBuf>,
+    pub exe_name: String,
+    pub title: String,
     pub label: String,
 }
 
@@ -49,6 +56,14 @@ struct WindowCandidate {
 
 struct FindWindowByNameData {
     target_name: String,
+    /// Lowercased `window_title_match` pattern; windows whose title doesn't
+    /// match are scored out.
+    title_filter: Option<String>,
+    candidates: Vec<WindowCandidate>,
+}
+
+struct FindWindowsByPidData {
+    pid: u32,
     candidates: Vec<WindowCandidate>,
 }
 
@@ -84,14 +99,19 @@ pub fn wait_for_window(pid: u32, timeout_ms: u64) -> Option<WindowFound> {
 }
 
 /// Poll for a visible window whose owning process exe name matches `process_name`
-/// (case-insensitive). Returns rich info about the found window.
-pub fn wait_for_window_by_name(process_name: &str, timeout_ms: u64) -> Option<WindowFound> {
+/// (case-insensitive) and, if given, whose title matches `title_match`.
+/// Returns rich info about the found window.
+pub fn wait_for_window_by_name(
+    process_name: &str,
+    title_match: Option<&str>,
+    timeout_ms: u64,
+) -> Option<WindowFound> {
     let target = process_name.to_lowercase();
     let start = std::time::Instant::now();
     let deadline = start + std::time::Duration::from_millis(timeout_ms);
 
     loop {
-        if let Some(found) = find_best_window_by_process_name(&target) {
+        if let Some(found) = find_best_window_by_process_name(&target, title_match) {
             let elapsed_ms = start.elapsed().as_millis() as u64;
             return Some(WindowFound {
                 elapsed_ms,
@@ -105,13 +125,41 @@ pub fn wait_for_window_by_name(process_name: &str, timeout_ms: u64) -> Option<Wi
     }
 }
 
-pub fn find_window_by_process_name(target_lowercase: &str) -> Option<HWND> {
-    find_best_window_by_process_name(target_lowercase).map(|f| f.hwnd)
+pub fn find_window_by_process_name(
+    target_lowercase: &str,
+    title_match: Option<&str>,
+) -> Option<HWND> {
+    find_best_window_by_process_name(target_lowercase, title_match).map(|f| f.hwnd)
+}
+
+/// Every plausible top-level window of the process, best candidate first.
+/// Tool windows and tiny helper windows are left out.
+pub fn find_all_windows_by_process_name(target_lowercase: &str) -> Vec<HWND> {
+    let mut data = FindWindowByNameData {
+        target_name: target_lowercase.to_string(),
+        title_filter: None,
+        candidates: Vec::new(),
+    };
+    unsafe {
+        let _ = EnumWindows(
+            Some(enum_window_by_name_callback),
+            LPARAM(&mut data as *mut _ as isize),
+        );
+    }
+    data.candidates.retain(|c| c.score > 0);
+    data.candidates.sort_by_key(|c| std::cmp::Reverse(c.score));
+    data.candidates.into_iter().map(|c| c.hwnd).collect()
 }
 
-fn find_best_window_by_process_name(target_lowercase: &str) -> Option<WindowFound> {
+fn find_best_window_by_process_name(
+    target_lowercase: &str,
+    title_match: Option<&str>,
+) -> Option<WindowFound> {
     let mut data = FindWindowByNameData {
         target_name: target_lowercase.to_string(),
+        title_filter: title_match
+            .map(|t| t.trim().to_lowercase())
+            .filter(|t| !t.is_empty()),
         candidates: Vec::new(),
     };
     unsafe {
@@ -164,47 +212,139 @@ unsafe extern "system" fn enum_window_by_name_callback(hwnd: HWND, lparam: LPARA
                     .to_string();
 
                 if exe_name == data.target_name {
-                    let mut score: i32 = 0;
-                    let mut w = 0i32;
-                    let mut h = 0i32;
-
-                    let ex_style = GetWindowLongW(hwnd, GWL_EXSTYLE) as u32;
-                    if ex_style & WS_EX_TOOLWINDOW.0 != 0 {
-                        score -= 50;
+                    let mut candidate = score_window(hwnd);
+                    if let Some(pattern) = &data.title_filter
+                        && !title_matches(&window_title(hwnd).to_lowercase(), pattern)
+                    {
+                        candidate.score -= 1000;
                     }
+                    data.candidates.push(candidate);
+                }
+            }
+            let _ = windows::Win32::Foundation::CloseHandle(hproc);
+        }
+        BOOL(1)
+    }
+}
 
-                    let mut wr = RECT::default();
-                    if GetWindowRect(hwnd, &mut wr).is_ok() {
-                        w = wr.right - wr.left;
-                        h = wr.bottom - wr.top;
-                        let area = (w * h).max(0);
-                        if w < 480 || h < 270 {
-                            score -= 100;
-                        } else {
-                            score += (area / 10_000).min(200);
-                        }
-                    }
+unsafe fn window_title(hwnd: HWND) -> String {
+    unsafe {
+        let len = GetWindowTextLengthW(hwnd);
+        if len <= 0 {
+            return String::new();
+        }
+        let mut buf = vec![0u16; (len + 1) as usize];
+        let copied = GetWindowTextW(hwnd, &mut buf);
+        String::from_utf16_lossy(&buf[..copied.max(0) as usize])
+    }
+}
 
-                    let title_len = GetWindowTextLengthW(hwnd);
-                    if title_len > 0 {
-                        score += 20;
-                    }
+/// `*` matches any run of characters; a pattern without `*` matches as a
+/// plain substring. Both sides are expected lowercased.
+fn title_matches(title: &str, pattern: &str) -> bool {
+    if !pattern.contains('*') {
+        return title.contains(pattern);
+    }
+    let parts: Vec<&str> = pattern.split('*').collect();
+    let (first, last) = (parts[0], parts[parts.len() - 1]);
+    if !title.starts_with(first) || !title[first.len()..].ends_with(last) {
+        return false;
+    }
+    let mut rest = &title[first.len()..title.len() - last.len()];
+    for part in &parts[1..parts.len() - 1] {
+        match rest.find(part) {
+            Some(at) => rest = &rest[at + part.len()..],
+            None => return false,
+        }
+    }
+    true
+}
 
-                    data.candidates.push(WindowCandidate {
-                        hwnd,
-                        score,
-                        width: w,
-                        height: h,
-                    });
-                }
+/// Rate how likely `hwnd` is to be an app's real window: big, titled windows
+/// score high; tool windows and tiny helpers go negative.
+unsafe fn score_window(hwnd: HWND) -> WindowCandidate {
+    unsafe {
+        let mut score: i32 = 0;
+        let mut w = 0i32;
+        let mut h = 0i32;
+
+        let ex_style = GetWindowLongW(hwnd, GWL_EXSTYLE) as u32;
+        if ex_style & WS_EX_TOOLWINDOW.0 != 0 {
+            score -= 50;
+        }
+
+        let mut wr = RECT::default();
+        if GetWindowRect(hwnd, &mut wr).is_ok() {
+            w = wr.right - wr.left;
+            h = wr.bottom - wr.top;
+            let area = (w * h).max(0);
+            if w < 480 || h < 270 {
+                score -= 100;
+            } else {
+                score += (area / 10_000).min(200);
             }
-            let _ = windows::Win32::Foundation::CloseHandle(hproc);
+        }
+
+        let title_len = GetWindowTextLengthW(hwnd);
+        if title_len > 0 {
+            score += 20;
+        }
+
+        WindowCandidate {
+            hwnd,
+            score,
+            width: w,
+            height: h,
+        }
+    }
+}
+
+/// Every plausible top-level window owned by `pid`, best candidate first.
+pub fn find_all_windows_by_pid(pid: u32) -> Vec<HWND> {
+    let mut data = FindWindowsByPidData {
+        pid,
+        candidates: Vec::new(),
+    };
+    unsafe {
+        let _ = EnumWindows(
+            Some(enum_windows_by_pid_callback),
+            LPARAM(&mut data as *mut _ as isize),
+        );
+    }
+    data.candidates.retain(|c| c.score > 0);
+    data.candidates.sort_by_key(|c| std::cmp::Reverse(c.score));
+    data.candidates.into_iter().map(|c| c.hwnd).collect()
+}
+
+unsafe extern "system" fn enum_windows_by_pid_callback(hwnd: HWND, lparam: LPARAM) -> BOOL {
+    unsafe {
+        if !IsWindowVisible(hwnd).as_bool() {
+            return BOOL(1);
+        }
+        let data = &mut *(lparam.0 as *mut FindWindowsByPidData);
+        let mut pid: u32 = 0;
+        GetWindowThreadProcessId(hwnd, Some(&mut pid));
+        if pid == data.pid {
+            data.candidates.push(score_window(hwnd));
         }
         BOOL(1)
     }
 }
 
-fn find_main_window_by_pid(pid: u32) -> Option<HWND> {
+/// Move every window of `pid` onto `target_rect`, tiling them when there is
+/// more than one. Returns how many windows were found and how many of them
+/// actually moved.
+pub fn move_all_windows_by_pid(pid: u32, target_rect: RECT) -> (usize, usize) {
+    let hwnds = find_all_windows_by_pid(pid);
+    let moved = hwnds
+        .iter()
+        .zip(tile_rects(target_rect, hwnds.len()))
+        .filter(|&(&hwnd, cell)| place_window_at(hwnd, cell))
+        .count();
+    (hwnds.len(), moved)
+}
+
+pub fn find_main_window_by_pid(pid: u32) -> Option<HWND> {
     let mut data = FindWindowData {
         pid,
         hwnd: HWND(ptr::null_mut()),
@@ -236,6 +376,31 @@ unsafe extern "system" fn enum_window_callback(hwnd: HWND, lparam: LPARAM) -> BO
     }
 }
 
+/// Pin `hwnd` above all non-topmost windows, or unpin it. Leaves its
+/// position and size alone. False if Windows refused, e.g. the window
+/// belongs to an elevated app.
+pub fn set_always_on_top(hwnd: HWND, on_top: bool) -> bool {
+    let insert_after = if on_top { HWND_TOPMOST } else { HWND_NOTOPMOST };
+    unsafe {
+        SetWindowPos(
+            hwnd,
+            Some(insert_after),
+            0,
+            0,
+            0,
+            0,
+            SWP_NOMOVE | SWP_NOSIZE | SWP_NOACTIVATE,
+        )
+        .is_ok()
+    }
+}
+
+/// Whether `hwnd` currently has the topmost (`WS_EX_TOPMOST`) style.
+pub fn is_always_on_top(hwnd: HWND) -> bool {
+    let ex_style = unsafe { GetWindowLongW(hwnd, GWL_EXSTYLE) } as u32;
+    ex_style & WS_EX_TOPMOST.0 != 0
+}
+
 fn monitor_for_rect(rect: RECT) -> HMONITOR {
     let w = rect.right - rect.left;
     let h = rect.bottom - rect.top;
@@ -250,19 +415,67 @@ fn monitor_for_rect(rect: RECT) -> HMONITOR {
     }
 }
 
-pub fn move_window_once(hwnd: HWND, target_rect: RECT) {
+/// The exact rect `mode` puts `hwnd` at within `area`, or `None` for the
+/// modes that fill the monitor via the maximize state.
+fn placement_rect(hwnd: HWND, area: RECT, mode: WindowPlacementMode) -> Option<RECT> {
+    let mut cur = RECT::default();
+    unsafe {
+        let _ = GetWindowRect(hwnd, &mut cur);
+    }
+    placement_within(area, mode, (cur.right - cur.left, cur.bottom - cur.top))
+}
+
+/// [`placement_rect`] for a window of `size` (width, height), which only
+/// `Centered` uses. Also drives the monitor preview's placement outline.
+pub fn placement_within(area: RECT, mode: WindowPlacementMode, size: (i32, i32)) -> Option<RECT> {
+    let (l, t, r, b) = (area.left, area.top, area.right, area.bottom);
+    let (mx, my) = (l + (r - l) / 2, t + (b - t) / 2);
+    let rect = |left, top, right, bottom| {
+        Some(RECT {
+            left,
+            top,
+            right,
+            bottom,
+        })
+    };
+    match mode {
+        WindowPlacementMode::Auto | WindowPlacementMode::Maximize => None,
+        WindowPlacementMode::Fill => Some(area),
+        WindowPlacementMode::Centered => {
+            let w = size.0.clamp(1, r - l);
+            let h = size.1.clamp(1, b - t);
+            let (x, y) = (l + (r - l - w) / 2, t + (b - t - h) / 2);
+            rect(x, y, x + w, y + h)
+        }
+        WindowPlacementMode::LeftHalf => rect(l, t, mx, b),
+        WindowPlacementMode::RightHalf => rect(mx, t, r, b),
+        WindowPlacementMode::TopLeft => rect(l, t, mx, my),
+        WindowPlacementMode::TopRight => rect(mx, t, r, my),
+        WindowPlacementMode::BottomLeft => rect(l, my, mx, b),
+        WindowPlacementMode::BottomRight => rect(mx, my, r, b),
+    }
+}
+
+pub fn move_window_once(hwnd: HWND, target_rect: RECT, mode: WindowPlacementMode) {
     let w = target_rect.right - target_rect.left;
     let h = target_rect.bottom - target_rect.top;
     unsafe {
         if !IsWindow(Some(hwnd)).as_bool() {
             return;
         }
+        if let Some(rect) = placement_rect(hwnd, target_rect, mode) {
+            place_window_at(hwnd, rect);
+            let _ = BringWindowToTop(hwnd);
+            let _ = SetForegroundWindow(hwnd);
+            return;
+        }
         let mut placement = WINDOWPLACEMENT {
             length: std::mem::size_of::<WINDOWPLACEMENT>() as u32,
             ..Default::default()
         };
         let _ = GetWindowPlacement(hwnd, &mut placement);
-        let was_maximized = placement.showCmd == SW_MAXIMIZE.0 as u32
+        let was_maximized = mode == WindowPlacementMode::Maximize
+            || placement.showCmd == SW_MAXIMIZE.0 as u32
             || placement.showCmd == SW_SHOWMAXIMIZED.0 as u32;
 
         let win_w = (w * 3) / 4;
@@ -297,22 +510,306 @@ pub fn move_window_once(hwnd: HWND, target_rect: RECT) {
     }
 }
 
-/// Silently watch a window for `watch_secs` seconds and nudge it back if it
-/// drifts off the target monitor. Call this in a background thread after the
-/// initial move so it never blocks the caller.
-pub fn watch_window_on_monitor(hwnd: HWND, target_rect: RECT, watch_secs: u64) {
+/// Minimize every visible window not owned by one of `keep_pids`.
+/// Returns the windows this minimized, leaving out ones that already were,
+/// so they can be restored later.
+pub fn minimize_other_windows(keep_pids: &[u32]) -> Vec<HWND> {
+    let others: Vec<HWND> = list_visible_windows()
+        .into_iter()
+        .filter(|e| !keep_pids.contains(&e.pid))
+        .map(|e| e.hwnd)
+        .filter(|&hwnd| unsafe { !IsIconic(hwnd).as_bool() })
+        .collect();
+    for &hwnd in &others {
+        unsafe {
+            let _ = ShowWindow(hwnd, SW_MINIMIZE);
+        }
+    }
+    others
+}
+
+/// Restore windows returned by [`minimize_other_windows`] that still exist.
+pub fn restore_windows(hwnds: &[HWND]) {
+    for &hwnd in hwnds {
+        unsafe {
+            if IsWindow(Some(hwnd)).as_bool() {
+                let _ = ShowWindow(hwnd, SW_RESTORE);
+            }
+        }
+    }
+}
+
+/// Split `area` into a near-square grid of `count` cells, row by row.
+pub fn tile_rects(area: RECT, count: usize) -> Vec<RECT> {
+    if count == 0 {
+        return vec![];
+    }
+    let cols = (count as f64).sqrt().ceil() as i32;
+    let rows = (count as i32 + cols - 1) / cols;
+    let cell_w = (area.right - area.left) / cols;
+    let cell_h = (area.bottom - area.top) / rows;
+    (0..count as i32)
+        .map(|i| {
+            let (row, col) = (i / cols, i % cols);
+            RECT {
+                left: area.left + col * cell_w,
+                top: area.top + row * cell_h,
+                right: area.left + (col + 1) * cell_w,
+                bottom: area.top + (row + 1) * cell_h,
+            }
+        })
+        .collect()
+}
+
+/// Un-maximize the window and put it exactly at `rect`. Returns whether
+/// Windows accepted the move; it refuses for windows of elevated apps when
+/// DisplayWarp isn't elevated.
+pub fn place_window_at(hwnd: HWND, rect: RECT) -> bool {
+    unsafe {
+        if !IsWindow(Some(hwnd)).as_bool() {
+            return false;
+        }
+        let _ = ShowWindow(hwnd, SW_RESTORE);
+        SetWindowPos(
+            hwnd,
+            Some(HWND_TOP),
+            rect.left,
+            rect.top,
+            rect.right - rect.left,
+            rect.bottom - rect.top,
+            SWP_SHOWWINDOW | SWP_FRAMECHANGED,
+        )
+        .is_ok()
+    }
+}
+
+/// `(hwnd, GWL_STYLE, GWL_EXSTYLE)` of windows made borderless by
+/// [`make_borderless`], so [`restore_window_frame`] can put the frame back.
+static BORDERLESS_STYLES: parking_lot::Mutex<Vec<(isize, i32, i32)>> =
+    parking_lot::Mutex::new(Vec::new());
+
+/// Strip the title bar and frame from `hwnd` and stretch it over `rect`.
+pub fn make_borderless(hwnd: HWND, rect: RECT) {
+    const FRAME: u32 =
+        WS_CAPTION.0 | WS_THICKFRAME.0 | WS_MINIMIZEBOX.0 | WS_MAXIMIZEBOX.0 | WS_SYSMENU.0;
+    const FRAME_EX: u32 =
+        WS_EX_DLGMODALFRAME.0 | WS_EX_CLIENTEDGE.0 | WS_EX_STATICEDGE.0 | WS_EX_WINDOWEDGE.0;
+    unsafe {
+        if !IsWindow(Some(hwnd)).as_bool() {
+            return;
+        }
+        let style = GetWindowLongW(hwnd, GWL_STYLE);
+        let ex_style = GetWindowLongW(hwnd, GWL_EXSTYLE);
+        {
+            let mut saved = BORDERLESS_STYLES.lock();
+            saved.retain(|s| IsWindow(Some(HWND(s.0 as *mut _))).as_bool());
+            // Re-applying after the game reset its styles must not overwrite
+            // the original frame with the half-stripped one.
+            if !saved.iter().any(|s| s.0 == hwnd.0 as isize) {
+                saved.push((hwnd.0 as isize, style, ex_style));
+            }
+        }
+        let _ = ShowWindow(hwnd, SW_RESTORE);
+        SetWindowLongW(hwnd, GWL_STYLE, (style as u32 & !FRAME) as i32);
+        SetWindowLongW(hwnd, GWL_EXSTYLE, (ex_style as u32 & !FRAME_EX) as i32);
+        let _ = SetWindowPos(
+            hwnd,
+            Some(HWND_TOP),
+            rect.left,
+            rect.top,
+            rect.right - rect.left,
+            rect.bottom - rect.top,
+            SWP_SHOWWINDOW | SWP_FRAMECHANGED,
+        );
+    }
+}
+
+/// Put back the styles saved by [`make_borderless`]. False if the window
+/// was never made borderless (or already restored).
+pub fn restore_window_frame(hwnd: HWND) -> bool {
+    let saved = {
+        let mut saved = BORDERLESS_STYLES.lock();
+        match saved.iter().position(|s| s.0 == hwnd.0 as isize) {
+            Some(i) => saved.remove(i),
+            None => return false,
+        }
+    };
+    unsafe {
+        SetWindowLongW(hwnd, GWL_STYLE, saved.1);
+        SetWindowLongW(hwnd, GWL_EXSTYLE, saved.2);
+        let _ = SetWindowPos(
+            hwnd,
+            None,
+            0,
+            0,
+            0,
+            0,
+            SWP_FRAMECHANGED | SWP_NOMOVE | SWP_NOSIZE | SWP_NOZORDER,
+        );
+    }
+    true
+}
+
+fn is_borderless(hwnd: HWND) -> bool {
+    BORDERLESS_STYLES
+        .lock()
+        .iter()
+        .any(|s| s.0 == hwnd.0 as isize)
+}
+
+/// Restored rect and maximized state of `hwnd` as `GetWindowPlacement`
+/// reports them. The rect is in workspace coordinates.
+pub fn capture_window_geometry(hwnd: HWND) -> Option<(RECT, bool)> {
+    let mut placement = WINDOWPLACEMENT {
+        length: std::mem::size_of::<WINDOWPLACEMENT>() as u32,
+        ..Default::default()
+    };
+    unsafe {
+        GetWindowPlacement(hwnd, &mut placement).ok()?;
+    }
+    let maximized =
+        placement.showCmd == SW_MAXIMIZE.0 as u32 || placement.showCmd == SW_SHOWMAXIMIZED.0 as u32;
+    Some((placement.rcNormalPosition, maximized))
+}
+
+/// The window's full placement, for putting it back later with
+/// [`restore_window_placement`].
+pub fn get_window_placement(hwnd: HWND) -> Option<WINDOWPLACEMENT> {
+    let mut placement = WINDOWPLACEMENT {
+        length: std::mem::size_of::<WINDOWPLACEMENT>() as u32,
+        ..Default::default()
+    };
+    unsafe { GetWindowPlacement(hwnd, &mut placement).ok()? };
+    Some(placement)
+}
+
+/// Restore a placement from [`get_window_placement`]. False when the window
+/// has been closed since.
+pub fn restore_window_placement(hwnd: HWND, placement: &WINDOWPLACEMENT) -> bool {
+    unsafe {
+        if !IsWindow(Some(hwnd)).as_bool() {
+            return false;
+        }
+        let _ = SetWindowPlacement(hwnd, placement);
+    }
+    true
+}
+
+/// Put `hwnd` back at geometry from [`capture_window_geometry`].
+pub fn apply_window_geometry(hwnd: HWND, normal: RECT, maximized: bool) {
+    unsafe {
+        if !IsWindow(Some(hwnd)).as_bool() {
+            return;
+        }
+        let mut placement = WINDOWPLACEMENT {
+            length: std::mem::size_of::<WINDOWPLACEMENT>() as u32,
+            ..Default::default()
+        };
+        let _ = GetWindowPlacement(hwnd, &mut placement);
+        placement.rcNormalPosition = normal;
+        placement.showCmd = if maximized {
+            SW_MAXIMIZE.0 as u32
+        } else {
+            SW_RESTORE.0 as u32
+        };
+        let _ = SetWindowPlacement(hwnd, &placement);
+        let _ = BringWindowToTop(hwnd);
+        let _ = SetForegroundWindow(hwnd);
+    }
+}
+
+/// Outcome of [`move_window_verified`].
+pub enum Placement {
+    /// Landed on the target monitor first time.
+    OnTarget,
+    /// First move missed (window was at `missed`); the corrective move worked.
+    Corrected { missed: RECT },
+    /// Still off the target monitor after the corrective move. `elevated` is
+    /// set when the window's process is elevated and DisplayWarp is not.
+    OffTarget { landed: RECT, elevated: bool },
+    /// Window closed before it could be checked.
+    Gone,
+}
+
+/// The window's rect if it is not on the monitor containing `target_rect`.
+fn off_target_rect(hwnd: HWND, target_rect: RECT) -> Option<RECT> {
+    unsafe {
+        if MonitorFromWindow(hwnd, MONITOR_DEFAULTTONEAREST) == monitor_for_rect(target_rect) {
+            return None;
+        }
+        let mut rect = RECT::default();
+        let _ = GetWindowRect(hwnd, &mut rect);
+        Some(rect)
+    }
+}
+
+/// [`move_window_once`], then re-read where the window actually ended up and
+/// make one corrective move if it missed the target monitor.
+pub fn move_window_verified(hwnd: HWND, target_rect: RECT, mode: WindowPlacementMode) -> Placement {
+    let settle = std::time::Duration::from_millis(150);
+
+    move_window_once(hwnd, target_rect, mode);
+    std::thread::sleep(settle);
+    if unsafe { !IsWindow(Some(hwnd)).as_bool() } {
+        return Placement::Gone;
+    }
+    let Some(missed) = off_target_rect(hwnd, target_rect) else {
+        return Placement::OnTarget;
+    };
+
+    move_window_once(hwnd, target_rect, mode);
+    std::thread::sleep(settle);
+    if unsafe { !IsWindow(Some(hwnd)).as_bool() } {
+        return Placement::Gone;
+    }
+    match off_target_rect(hwnd, target_rect) {
+        None => Placement::Corrected { missed },
+        Some(landed) => Placement::OffTarget {
+            landed,
+            elevated: crate::elevation::blocked_by_elevation(hwnd),
+        },
+    }
+}
+
+/// Silently watch a window for `watch_secs` seconds (`None`: until it
+/// closes) and nudge it back if it drifts off the target monitor. With
+/// `borderless`, also strip the frame again if the app restores it. Call this
+/// in a background thread after the initial move so it never blocks the caller.
+pub fn watch_window_on_monitor(
+    hwnd: HWND,
+    target_rect: RECT,
+    watch_secs: Option<u64>,
+    mode: WindowPlacementMode,
+    borderless: bool,
+) {
     let w = target_rect.right - target_rect.left;
     let h = target_rect.bottom - target_rect.top;
     let target_mon = monitor_for_rect(target_rect);
-    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(watch_secs);
+    let deadline =
+        watch_secs.map(|s| std::time::Instant::now() + std::time::Duration::from_secs(s));
 
-    while std::time::Instant::now() < deadline {
+    while deadline.is_none_or(|d| std::time::Instant::now() < d) {
         std::thread::sleep(std::time::Duration::from_millis(1000));
         unsafe {
             if !IsWindow(Some(hwnd)).as_bool() {
                 return;
             }
+            if borderless {
+                // Frame restored by the user: stop fighting them.
+                if !is_borderless(hwnd) {
+                    return;
+                }
+                let framed = GetWindowLongW(hwnd, GWL_STYLE) as u32 & WS_CAPTION.0 != 0;
+                if framed || MonitorFromWindow(hwnd, MONITOR_DEFAULTTONEAREST) != target_mon {
+                    make_borderless(hwnd, target_rect);
+                }
+                continue;
+            }
             if MonitorFromWindow(hwnd, MONITOR_DEFAULTTONEAREST) != target_mon {
+                if mode != WindowPlacementMode::Auto {
+                    move_window_once(hwnd, target_rect, mode);
+                    continue;
+                }
                 let _ = BringWindowToTop(hwnd);
                 let _ = SetForegroundWindow(hwnd);
                 let _ = ShowWindow(hwnd, SW_RESTORE);
@@ -413,16 +910,250 @@ pub fn move_to_monitor(hwnd: HWND, target_rect: RECT) {
     }
 }
 
-#[allow(dead_code)]
+// ─── Overlay windows ──────────────────────────────────────────────────────────
+
+unsafe extern "system" fn overlay_wndproc(
+    hwnd: HWND,
+    msg: u32,
+    wparam: windows::Win32::Foundation::WPARAM,
+    lparam: LPARAM,
+) -> windows::Win32::Foundation::LRESULT {
+    use windows::Win32::UI::WindowsAndMessaging::{DefWindowProcW, DestroyWindow, WM_LBUTTONDOWN};
+    unsafe {
+        // Clicking a (non click-through) overlay dismisses it.
+        if msg == WM_LBUTTONDOWN {
+            let _ = DestroyWindow(hwnd);
+            return windows::Win32::Foundation::LRESULT(0);
+        }
+        DefWindowProcW(hwnd, msg, wparam, lparam)
+    }
+}
+
+/// Create a borderless topmost popup covering `rect`, painted with `color`
+/// (a `0x00BBGGRR` COLORREF). `alpha` below 255 makes it translucent and
+/// click-through. Must be pumped by the creating thread; see [`pump_messages`].
+fn create_overlay(
+    class_name: windows::core::PCWSTR,
+    rect: RECT,
+    color: u32,
+    alpha: u8,
+) -> Option<HWND> {
+    use windows::Win32::Foundation::{COLORREF, HINSTANCE};
+    use windows::Win32::Graphics::Gdi::{CreateSolidBrush, DeleteObject, HGDIOBJ};
+    use windows::Win32::System::LibraryLoader::GetModuleHandleW;
+    use windows::Win32::UI::WindowsAndMessaging::{
+        CreateWindowExW, LWA_ALPHA, RegisterClassW, SW_SHOWNOACTIVATE, SetLayeredWindowAttributes,
+        WINDOW_EX_STYLE, WNDCLASSW, WS_EX_LAYERED, WS_EX_NOACTIVATE, WS_EX_TRANSPARENT, WS_POPUP,
+    };
+
+    unsafe {
+        let hinstance: HINSTANCE = GetModuleHandleW(None).ok()?.into();
+        let brush = CreateSolidBrush(COLORREF(color));
+        let class = WNDCLASSW {
+            lpfnWndProc: Some(overlay_wndproc),
+            hInstance: hinstance,
+            hbrBackground: brush,
+            lpszClassName: class_name,
+            ..Default::default()
+        };
+        // The class (and its brush) is registered once per process; later calls
+        // fail with ERROR_CLASS_ALREADY_EXISTS and free their spare brush.
+        if RegisterClassW(&class) == 0 {
+            let _ = DeleteObject(HGDIOBJ(brush.0));
+        }
+
+        let mut ex_style: WINDOW_EX_STYLE = WS_EX_TOPMOST | WS_EX_TOOLWINDOW | WS_EX_NOACTIVATE;
+        if alpha < 255 {
+            ex_style |= WS_EX_LAYERED | WS_EX_TRANSPARENT;
+        }
+        let overlay = CreateWindowExW(
+            ex_style,
+            class_name,
+            windows::core::w!("DisplayWarp Overlay"),
+            WS_POPUP,
+            rect.left,
+            rect.top,
+            rect.right - rect.left,
+            rect.bottom - rect.top,
+            None,
+            None,
+            Some(hinstance),
+            None,
+        )
+        .ok()?;
+        if alpha < 255 {
+            let _ = SetLayeredWindowAttributes(overlay, COLORREF(0), alpha, LWA_ALPHA);
+        }
+        let _ = ShowWindow(overlay, SW_SHOWNOACTIVATE);
+        Some(overlay)
+    }
+}
+
+/// Drain the calling thread's message queue.
+fn pump_messages() {
+    use windows::Win32::UI::WindowsAndMessaging::{
+        DispatchMessageW, MSG, PM_REMOVE, PeekMessageW, TranslateMessage,
+    };
+    unsafe {
+        let mut msg = MSG::default();
+        while PeekMessageW(&mut msg, None, 0, 0, PM_REMOVE).as_bool() {
+            let _ = TranslateMessage(&msg);
+            DispatchMessageW(&msg);
+        }
+    }
+}
+
+/// Flash a translucent, click-through rectangle over `rect` for `duration_ms`.
+/// Blocks the calling thread while the overlay is shown, so spawn it in the
+/// background from the UI.
+pub fn flash_placement_rect(rect: RECT, duration_ms: u64) {
+    use windows::Win32::UI::WindowsAndMessaging::DestroyWindow;
+
+    // Purple accent, matching the primary-monitor colour in the UI.
+    let Some(overlay) = create_overlay(
+        windows::core::w!("DisplayWarpPlacementOverlay"),
+        rect,
+        0x00F6_5C8B,
+        110,
+    ) else {
+        return;
+    };
+    let deadline = std::time::Instant::now() + std::time::Duration::from_millis(duration_ms);
+    while std::time::Instant::now() < deadline {
+        pump_messages();
+        std::thread::sleep(std::time::Duration::from_millis(16));
+    }
+    unsafe {
+        let _ = DestroyWindow(overlay);
+    }
+}
+
+/// Cover each of `rects` with a black topmost window for as long as `watched`
+/// exists. Clicking a blackout dismisses it early. Returns how many monitors
+/// were blanked. Blocks, so call it from a background thread.
+pub fn blank_monitors_while_window(rects: &[RECT], watched: HWND) -> usize {
+    use windows::Win32::UI::WindowsAndMessaging::DestroyWindow;
+
+    let overlays: Vec<HWND> = rects
+        .iter()
+        .filter_map(|r| create_overlay(windows::core::w!("DisplayWarpBlackout"), *r, 0, 255))
+        .collect();
+    let count = overlays.len();
+
+    unsafe {
+        while IsWindow(Some(watched)).as_bool()
+            && overlays.iter().any(|o| IsWindow(Some(*o)).as_bool())
+        {
+            pump_messages();
+            std::thread::sleep(std::time::Duration::from_millis(50));
+        }
+        for o in overlays {
+            if IsWindow(Some(o)).as_bool() {
+                let _ = DestroyWindow(o);
+            }
+        }
+    }
+    count
+}
+
+/// Block until the process behind `process` exits.
+///
+/// Takes the handle captured from the spawned `Child` rather than re-opening by
+/// PID, so a recycled PID can never make us wait on (or restore after) the
+/// wrong process.
+pub fn wait_for_process_exit(process: &std::os::windows::io::OwnedHandle) {
+    use std::os::windows::io::AsRawHandle;
+    unsafe {
+        let _ = WaitForSingleObject(
+            windows::Win32::Foundation::HANDLE(process.as_raw_handle()),
+            INFINITE,
+        );
+    }
+}
+
+/// Block until the process with `pid` exits. Only use this for processes we
+/// did not spawn ourselves; prefer [`wait_for_process_exit`] otherwise.
 pub fn wait_for_pid_exit(pid: u32) {
     unsafe {
         if let Ok(hproc) = OpenProcess(PROCESS_SYNCHRONIZE, false, pid) {
-            let _ = WaitForSingleObject(hproc, u32::MAX);
+            let _ = WaitForSingleObject(hproc, INFINITE);
             let _ = windows::Win32::Foundation::CloseHandle(hproc);
         }
     }
 }
 
+/// PID of the process that owns `hwnd`, or 0 if the window is gone.
+pub fn window_pid(hwnd: HWND) -> u32 {
+    let mut pid: u32 = 0;
+    unsafe {
+        GetWindowThreadProcessId(hwnd, Some(&mut pid));
+    }
+    pid
+}
+
+/// Creation time of the process behind `hproc`, as a raw FILETIME.
+fn handle_start_time(hproc: windows::Win32::Foundation::HANDLE) -> Option<u64> {
+    use windows::Win32::Foundation::FILETIME;
+    let (mut created, mut exited, mut kernel, mut user) = (
+        FILETIME::default(),
+        FILETIME::default(),
+        FILETIME::default(),
+        FILETIME::default(),
+    );
+    unsafe { GetProcessTimes(hproc, &mut created, &mut exited, &mut kernel, &mut user) }
+        .ok()
+        .map(|()| ((created.dwHighDateTime as u64) << 32) | created.dwLowDateTime as u64)
+}
+
+/// Creation time of process `pid`. Together with the PID it identifies one
+/// process, since Windows reuses PIDs but never with the same start time.
+pub fn process_start_time(pid: u32) -> Option<u64> {
+    unsafe {
+        let hproc = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid).ok()?;
+        let started = handle_start_time(hproc);
+        let _ = windows::Win32::Foundation::CloseHandle(hproc);
+        started
+    }
+}
+
+/// Forcefully end process `pid` and wait for it to exit. With `started`, the
+/// process is left alone unless it still has that start time, so a reused
+/// PID is never killed. Returns false if it couldn't be opened or killed,
+/// e.g. an elevated process seen from a non-elevated DisplayWarp.
+pub fn terminate_process(pid: u32, started: Option<u64>) -> bool {
+    unsafe {
+        let Ok(hproc) = OpenProcess(
+            PROCESS_TERMINATE | PROCESS_SYNCHRONIZE | PROCESS_QUERY_LIMITED_INFORMATION,
+            false,
+            pid,
+        ) else {
+            return false;
+        };
+        let same = started.is_none_or(|s| handle_start_time(hproc) == Some(s));
+        let ok = same && TerminateProcess(hproc, 1).is_ok();
+        if ok {
+            let _ = WaitForSingleObject(hproc, INFINITE);
+        }
+        let _ = windows::Win32::Foundation::CloseHandle(hproc);
+        ok
+    }
+}
+
+/// Visible windows sitting on the monitor at `source`, or, with `None`, windows
+/// that no longer touch any active monitor (e.g. after a screen died).
+pub fn stranded_windows(source: Option<RECT>) -> Vec<ProcessEntry> {
+    let source_mon = source.map(monitor_for_rect);
+    list_visible_windows()
+        .into_iter()
+        .filter(|e| unsafe {
+            match source_mon {
+                Some(mon) => MonitorFromWindow(e.hwnd, MONITOR_DEFAULTTONEAREST) == mon,
+                None => MonitorFromWindow(e.hwnd, MONITOR_DEFAULTTONULL).is_invalid(),
+            }
+        })
+        .collect()
+}
+
 pub fn list_visible_windows() -> Vec<ProcessEntry> {
     let mut entries: Vec<ProcessEntry> = Vec::new();
     unsafe {
@@ -435,19 +1166,38 @@ pub fn list_visible_windows() -> Vec<ProcessEntry> {
     entries
 }
 
-unsafe extern "system" fn enum_visible_windows_callback(hwnd: HWND, lparam: LPARAM) -> BOOL {
+/// Whether `hwnd` is an app window the user could want to move: visible,
+/// titled, not a tool window, and not part of the shell (desktop, taskbar).
+/// The filter behind [`list_visible_windows`].
+pub fn is_app_window(hwnd: HWND) -> bool {
+    use windows::Win32::UI::WindowsAndMessaging::GetClassNameW;
+    const SHELL_CLASSES: [&str; 4] = [
+        "Progman",
+        "WorkerW",
+        "Shell_TrayWnd",
+        "Shell_SecondaryTrayWnd",
+    ];
     unsafe {
         if !IsWindowVisible(hwnd).as_bool() {
-            return BOOL(1);
+            return false;
         }
         let ex_style = GetWindowLongW(hwnd, GWL_EXSTYLE) as u32;
-        if ex_style & WS_EX_TOOLWINDOW.0 != 0 {
-            return BOOL(1);
+        if ex_style & WS_EX_TOOLWINDOW.0 != 0 || GetWindowTextLengthW(hwnd) <= 0 {
+            return false;
         }
-        let title_len = GetWindowTextLengthW(hwnd);
-        if title_len <= 0 {
+        let mut class = [0u16; 64];
+        let len = GetClassNameW(hwnd, &mut class).max(0) as usize;
+        let class = String::from_utf16_lossy(&class[..len]);
+        !SHELL_CLASSES.contains(&class.as_str())
+    }
+}
+
+unsafe extern "system" fn enum_visible_windows_callback(hwnd: HWND, lparam: LPARAM) -> BOOL {
+    unsafe {
+        if !is_app_window(hwnd) {
             return BOOL(1);
         }
+        let title_len = GetWindowTextLengthW(hwnd);
         let mut title_buf = vec![0u16; (title_len + 1) as usize];
         GetWindowTextW(hwnd, &mut title_buf);
         let title = String::from_utf16_lossy(&title_buf)
@@ -494,6 +1244,8 @@ unsafe extern "system" fn enum_visible_windows_callback(hwnd: HWND, lparam: LPAR
             pid,
             exe_path,
             label: format!("{exe_name} — {title}"),
+            exe_name,
+            title,
         });
 
         BOOL(1)